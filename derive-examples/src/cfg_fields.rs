@@ -0,0 +1,29 @@
+//! This module shows how `#[cfg]`, `#[cfg_attr]` and `#[deprecated]` on fields are carried over
+//! to the generated code
+//!
+
+use derived::{Constdef, Ctor, Gtor, Stor};
+
+#[derive(Constdef, Ctor, Gtor, Stor)]
+pub struct Gated {
+    a: u8,
+    // `any()` is always false, so this field (and its getter, setter and ctor argument) is
+    // never compiled
+    #[cfg(any())]
+    b: String,
+    #[cfg_attr(all(), deprecated(note = "use `a` instead"))]
+    c: u8,
+}
+
+#[test]
+#[allow(deprecated)]
+fn test_cfg_fields() {
+    let mut gated = Gated::new(1, 2);
+    assert_eq!(gated.get_a(), 1);
+    // using these will warn, just like using `gated.c` would
+    assert_eq!(gated.get_c(), 2);
+    gated.set_c(3);
+    assert_eq!(gated.get_c(), 3);
+    let default = Gated::default();
+    assert_eq!(default.get_a(), 0);
+}
@@ -0,0 +1,52 @@
+//! This module shows the accessors for collection fields
+//!
+
+use derived::{Ctor, Gtor, Stor};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+
+#[derive(Ctor, Gtor, Stor)]
+#[gtor(collection)] // every collection field gets `*_len` and `iter_*`
+#[stor(collection, chain)]
+pub struct Team<K: Ord> {
+    #[stor(each = "member")] // `add_member` instead of `push_members`
+    members: Vec<String>,
+    backlog: VecDeque<u32>,
+    #[stor(each = "score")] // `add_score` and `remove_score`
+    scores: HashMap<String, u32>,
+    labels: BTreeSet<K>,
+    name: String, // not a collection, so this one gets the usual accessors
+}
+
+#[test]
+fn test_collection_fields() {
+    let mut team = Team::new(
+        vec![],
+        VecDeque::new(),
+        HashMap::new(),
+        BTreeSet::new(),
+        "a".into(),
+    );
+    team.add_member("sayan".to_owned())
+        .extend_members(vec!["ohsayan".to_owned()])
+        .push_backlog(1)
+        .extend_backlog([2, 3])
+        .set_name("core".to_owned());
+    assert_eq!(team.members_len(), 2);
+    assert_eq!(team.iter_backlog().sum::<u32>(), 6);
+    assert_eq!(team.add_score("sayan".to_owned(), 10), None);
+    assert_eq!(team.add_score("sayan".to_owned(), 20), Some(10));
+    assert_eq!(team.remove_score(&"sayan".to_owned()), Some(20));
+    assert_eq!(team.scores_len(), 0);
+    assert!(team.insert_labels(2u8));
+    assert!(!team.insert_labels(2));
+    team.extend_labels([1, 3]);
+    assert_eq!(
+        team.iter_labels().copied().collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+    assert!(team.remove_labels(&1));
+    team.clear_members().clear_labels();
+    assert_eq!(team.members_len(), 0);
+    assert_eq!(team.labels_len(), 0);
+    assert_eq!(team.get_name(), "core");
+}
@@ -0,0 +1,64 @@
+//! This module shows `ConstBounds` with nested structs, arrays, tuples and generics
+//!
+
+use derived::ConstBounds;
+use std::num::Wrapping;
+
+#[derive(ConstBounds, Debug, PartialEq, PartialOrd)]
+pub struct Version {
+    major: u16,
+    minor: u16,
+}
+
+#[derive(ConstBounds, Debug, PartialEq, PartialOrd)]
+pub struct SortKey<T> {
+    version: Version,
+    stable: bool,
+    checksum: [u8; 4],
+    range: (i32, f64),
+    counter: Wrapping<u8>,
+    extra: T,
+}
+
+// `Version` isn't `Copy`, so the arrays of it are built with `const` blocks
+#[derive(ConstBounds)]
+pub struct Releases {
+    latest: [(u8, Version); 2],
+}
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn test_const_bounds() {
+    assert_eq!(Version::MIN, Version { major: 0, minor: 0 });
+    assert_eq!(
+        Version::MAX,
+        Version {
+            major: u16::MAX,
+            minor: u16::MAX
+        }
+    );
+    const MIN: SortKey<[i8; 2]> = SortKey::MIN;
+    assert_eq!(MIN.version, Version::MIN);
+    assert!(!MIN.stable);
+    assert_eq!(MIN.checksum, [0; 4]);
+    assert_eq!(MIN.range, (i32::MIN, f64::MIN));
+    assert_eq!(MIN.counter, Wrapping(0));
+    assert_eq!(MIN.extra, [i8::MIN; 2]);
+    let max = SortKey::<char>::MAX;
+    assert!(max.stable);
+    assert_eq!(max.range, (i32::MAX, f64::MAX));
+    assert_eq!(max.extra, char::MAX);
+    let key = SortKey {
+        version: Version { major: 1, minor: 0 },
+        stable: true,
+        checksum: [1; 4],
+        range: (0, 0.0),
+        counter: Wrapping(3),
+        extra: 'x',
+    };
+    assert!(SortKey::MIN < key && key < max);
+    let releases = Releases::MAX;
+    assert_eq!(releases.latest[1].0, u8::MAX);
+    assert_eq!(releases.latest[1].1, Version::MAX);
+    assert_eq!(Releases::MIN.latest[0].1, Version::MIN);
+}
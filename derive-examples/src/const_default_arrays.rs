@@ -0,0 +1,33 @@
+//! This module shows `Constdef` with arrays of types that aren't `Copy`
+//!
+
+use derived::Constdef;
+use std::sync::Mutex;
+
+#[derive(Constdef)]
+pub struct Buckets<const N: usize> {
+    labels: [String; N],
+    buffers: [Vec<u8>; 4],
+    slots: [[Option<String>; 2]; 3],
+    // primitives still work just like they used to
+    sizes: [u32; N],
+}
+
+#[derive(Constdef)]
+pub struct Shards {
+    locks: [Mutex<u32>; 16],
+}
+
+static SHARDS: Shards = Shards::default();
+
+#[test]
+fn test_const_default_arrays() {
+    let buckets: Buckets<8> = Buckets::default();
+    assert!(buckets.labels.iter().all(String::is_empty));
+    assert!(buckets.buffers.iter().all(Vec::is_empty));
+    assert!(buckets.slots.iter().flatten().all(Option::is_none));
+    assert_eq!(buckets.sizes, [0; 8]);
+    *SHARDS.locks[15].lock().unwrap() += 1;
+    assert_eq!(*SHARDS.locks[15].lock().unwrap(), 1);
+    assert_eq!(*SHARDS.locks[0].lock().unwrap(), 0);
+}
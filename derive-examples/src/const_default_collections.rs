@@ -0,0 +1,30 @@
+//! This module shows `Constdef` with collection fields, for `static`s that are filled in later
+//!
+
+use derived::Constdef;
+use std::collections::{BTreeMap, BTreeSet, VecDeque};
+use std::sync::Mutex;
+
+#[derive(Constdef)]
+pub struct Registry {
+    name: String,
+    plugins: Vec<&'static str>,
+    // the full paths (and any element types) work too
+    versions: std::collections::BTreeMap<&'static str, (u8, u8)>,
+    tags: BTreeSet<String>,
+    queue: VecDeque<u32>,
+}
+
+static REGISTRY: Mutex<Registry> = Mutex::new(Registry::default());
+
+#[test]
+fn test_const_default_collections() {
+    let mut registry = REGISTRY.lock().unwrap();
+    assert!(registry.name.is_empty());
+    assert!(registry.tags.is_empty());
+    assert!(registry.queue.is_empty());
+    registry.plugins.push("stor");
+    registry.versions.insert("stor", (0, 4));
+    assert_eq!(registry.plugins, ["stor"]);
+    assert_eq!(registry.versions, BTreeMap::from([("stor", (0, 4))]));
+}
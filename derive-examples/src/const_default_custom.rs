@@ -0,0 +1,33 @@
+//! This module shows how to pick the const default values of specific fields
+//!
+
+use derived::Constdef;
+use std::num::NonZeroU16;
+
+#[derive(Constdef)]
+pub struct PoolConfig {
+    #[constdef(default = 16)]
+    max_connections: u32,
+    #[constdef(default = Self::DEFAULT_IDLE_SECS)]
+    idle_secs: u64,
+    // types without a zero value can be used too, as long as they have a default
+    #[constdef(default = match NonZeroU16::new(5432) { Some(port) => port, None => panic!() })]
+    port: NonZeroU16,
+    // the other fields get the usual defaults
+    read_only: bool,
+}
+
+impl PoolConfig {
+    const DEFAULT_IDLE_SECS: u64 = 300;
+}
+
+const POOL: PoolConfig = PoolConfig::default();
+
+#[test]
+#[allow(clippy::assertions_on_constants)]
+fn test_const_default_custom() {
+    assert_eq!(POOL.max_connections, 16);
+    assert_eq!(POOL.idle_secs, 300);
+    assert_eq!(POOL.port.get(), 5432);
+    assert!(!POOL.read_only);
+}
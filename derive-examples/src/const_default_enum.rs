@@ -0,0 +1,52 @@
+//! This module shows `Constdef` on enums, where one of the variants is the default
+//!
+
+use derived::Constdef;
+
+#[derive(Constdef, Debug, PartialEq)]
+pub enum Compression {
+    None,
+    #[constdef(default)]
+    Fast,
+    Best,
+}
+
+#[derive(Constdef, Debug, PartialEq)]
+pub enum Storage {
+    Memory,
+    // the fields of the default variant get their defaults too
+    #[constdef(default)]
+    Disk {
+        #[constdef(default = "/var/lib/app")]
+        path: &'static str,
+        compression: Compression,
+        max_files: u32,
+    },
+}
+
+#[derive(Constdef, Debug, PartialEq)]
+pub enum Retry {
+    Never,
+    #[constdef(default)]
+    Times(u8, Compression),
+}
+
+const STORAGE: Storage = Storage::default();
+
+#[test]
+fn test_const_default_enum() {
+    assert_eq!(Compression::default(), Compression::Fast);
+    assert_ne!(Compression::default(), Compression::None);
+    assert_ne!(Compression::default(), Compression::Best);
+    assert_ne!(STORAGE, Storage::Memory);
+    assert_ne!(Retry::default(), Retry::Never);
+    assert_eq!(
+        STORAGE,
+        Storage::Disk {
+            path: "/var/lib/app",
+            compression: Compression::Fast,
+            max_files: 0,
+        }
+    );
+    assert_eq!(Retry::default(), Retry::Times(0, Compression::Fast));
+}
@@ -0,0 +1,32 @@
+//! This module shows how `Constdef` structs can be nested, and used with type parameters
+//!
+
+use derived::{ConstDefault, Constdef};
+
+#[derive(Constdef)]
+pub struct Timeouts {
+    connect_ms: u32,
+    read_ms: u32,
+}
+
+#[derive(Constdef)]
+pub struct ServerConfig<T> {
+    port: u16,
+    // other `Constdef` structs (and anything else implementing `ConstDefault`) work as fields
+    timeouts: Timeouts,
+    backups: [Timeouts; 2],
+    // type parameters too, as long as they implement `ConstDefault`
+    extra: T,
+}
+
+const CONFIG: ServerConfig<(u8, bool)> = ServerConfig::default();
+
+#[test]
+fn test_const_default_nested() {
+    assert_eq!(CONFIG.port, 0);
+    assert_eq!(CONFIG.timeouts.connect_ms, 0);
+    assert_eq!(CONFIG.backups[1].read_ms, 0);
+    assert_eq!(CONFIG.extra, (0, false));
+    // the `ConstDefault` trait gives the same value
+    assert_eq!(<ServerConfig<u64> as ConstDefault>::DEFAULT.extra, 0);
+}
@@ -0,0 +1,32 @@
+//! This module shows how to configure the items that `Constdef` generates
+//!
+
+use derived::{ConstDefault, Constdef};
+
+// a `const fn const_default()` instead of `default()`, so that `Default::default` isn't shadowed
+#[derive(Constdef, Debug, PartialEq)]
+#[constdef(const_fn = const_default)]
+pub struct Window {
+    width: u32,
+    height: u32,
+}
+
+// only a `DEFAULT` constant, and the `Default` impl is derived instead
+#[derive(Constdef, Default, Debug, PartialEq)]
+#[constdef(no_const_fn, assoc_const, no_default_impl)]
+pub struct Theme {
+    dark: bool,
+    accent: (u8, u8, u8),
+}
+
+const WINDOW: Window = Window::const_default();
+
+#[test]
+fn test_const_default_output() {
+    assert_eq!(WINDOW, Window::default());
+    assert_eq!(<Window as ConstDefault>::DEFAULT.width, 0);
+    assert_eq!(Theme::DEFAULT, Theme::default());
+    assert_eq!(Theme::DEFAULT.accent, (0, 0, 0));
+    assert_eq!(WINDOW.height, 0);
+    assert!(!Theme::default().dark);
+}
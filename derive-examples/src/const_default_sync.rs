@@ -0,0 +1,37 @@
+//! This module shows `Constdef` with atomics and locks, for `static` state shared across threads
+//!
+
+use derived::Constdef;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, Once, OnceLock, RwLock};
+
+#[derive(Constdef)]
+pub struct Metrics {
+    requests: AtomicU64,
+    ready: AtomicBool,
+    // the locks are created holding the defaults for their types
+    slowest: Mutex<(u64, String)>,
+    buckets: RwLock<[u32; 4]>,
+    init: Once,
+    name: OnceLock<&'static str>,
+}
+
+static METRICS: Metrics = Metrics::default();
+
+#[test]
+fn test_const_default_sync() {
+    std::thread::spawn(|| {
+        METRICS.requests.fetch_add(1, Ordering::SeqCst);
+        METRICS.buckets.write().unwrap()[2] += 1;
+    })
+    .join()
+    .unwrap();
+    METRICS
+        .init
+        .call_once(|| METRICS.ready.store(true, Ordering::SeqCst));
+    assert_eq!(METRICS.requests.load(Ordering::SeqCst), 1);
+    assert!(METRICS.ready.load(Ordering::SeqCst));
+    assert_eq!(*METRICS.slowest.lock().unwrap(), (0, String::new()));
+    assert_eq!(*METRICS.buckets.read().unwrap(), [0, 0, 1, 0]);
+    assert_eq!(METRICS.name.get_or_init(|| "api"), &"api");
+}
@@ -0,0 +1,31 @@
+//! This module shows `Constdef` on tuple and unit structs, whose fields get their defaults by
+//! position
+//!
+
+use derived::Constdef;
+
+#[derive(Constdef, Debug, PartialEq)]
+pub struct Version(u8, u8, #[constdef(default = 1)] u16);
+
+#[derive(Constdef, Debug, PartialEq)]
+pub struct Release(Version, &'static str);
+
+#[derive(Constdef, Debug, PartialEq)]
+pub struct Marker;
+
+#[derive(Constdef, Debug, PartialEq)]
+#[constdef(zeroed)]
+#[repr(C)]
+pub struct Raw(u32, *const u8);
+
+const RELEASE: Release = Release::default();
+
+#[test]
+fn test_const_default_tuple() {
+    assert_eq!(Version::default(), Version(0, 0, 1));
+    assert_eq!(RELEASE, Release(Version(0, 0, 1), ""));
+    assert_eq!(Marker::default(), Marker);
+    let raw = Raw::default();
+    assert_eq!(raw.0, 0);
+    assert!(raw.1.is_null());
+}
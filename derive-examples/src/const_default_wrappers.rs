@@ -0,0 +1,41 @@
+//! This module shows `Constdef` with options, cells, references, pointers and other wrappers
+//!
+
+use derived::Constdef;
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+use std::num::Wrapping;
+use std::ptr::NonNull;
+use std::time::Duration;
+
+#[derive(Constdef)]
+pub struct Node<T> {
+    name: &'static str,
+    children: &'static [&'static str],
+    parent: Option<NonNull<Node<T>>>,
+    cached: Option<String>,
+    retry_after: Duration,
+    // these hold the defaults for their own types
+    visits: Cell<u32>,
+    log: RefCell<Vec<String>>,
+    checksum: Wrapping<u16>,
+    raw: *mut u8,
+    _marker: PhantomData<T>,
+}
+
+#[test]
+fn test_const_default_wrappers() {
+    // `Cell` isn't `Sync`, so this is a local instead of a `static`
+    let root: Node<String> = Node::default();
+    assert_eq!(root.name, "");
+    assert!(root.children.is_empty());
+    assert!(root.parent.is_none());
+    assert!(root.cached.is_none());
+    assert_eq!(root.retry_after, Duration::ZERO);
+    root.visits.set(root.visits.get() + 1);
+    root.log.borrow_mut().push("visited".to_owned());
+    assert_eq!(root.visits.get(), 1);
+    assert_eq!(root.log.borrow().len(), 1);
+    assert_eq!(root.checksum + Wrapping(u16::MAX), Wrapping(u16::MAX));
+    assert!(root.raw.is_null());
+}
@@ -0,0 +1,54 @@
+//! This module shows `Constdef` with `#[constdef(zeroed)]` on plain data structs
+//!
+
+use derived::Constdef;
+use std::cell::Cell;
+use std::marker::PhantomData;
+use std::num::Wrapping;
+use std::ptr::NonNull;
+use std::sync::atomic::{AtomicU32, Ordering};
+
+#[derive(Constdef)]
+#[constdef(zeroed)]
+#[repr(C)]
+pub struct Header {
+    magic: u32,
+    flags: [u8; 4],
+    count: AtomicU32,
+}
+
+#[derive(Constdef)]
+#[constdef(zeroed, assoc_const)]
+#[repr(C)]
+pub struct Packet<T> {
+    header: Header,
+    callback: Option<extern "C" fn(*mut u8)>,
+    data: Option<NonNull<u8>>,
+    raw: *const T,
+    range: (u16, i16),
+    weight: Cell<f32>,
+    checksum: Wrapping<u32>,
+    on_close: Option<unsafe extern "C" fn(*mut u8, usize) -> i32>,
+    items: [T; 2],
+    _marker: PhantomData<T>,
+}
+
+static HEADER: Header = Header::default();
+
+#[test]
+fn test_const_default_zeroed() {
+    assert_eq!(HEADER.magic, 0);
+    assert_eq!(HEADER.flags, [0; 4]);
+    HEADER.count.fetch_add(1, Ordering::SeqCst);
+    assert_eq!(HEADER.count.load(Ordering::SeqCst), 1);
+    let packet: Packet<u64> = Packet::DEFAULT;
+    assert_eq!(packet.header.magic, 0);
+    assert!(packet.callback.is_none());
+    assert!(packet.data.is_none());
+    assert!(packet.raw.is_null());
+    assert_eq!(packet.range, (0, 0));
+    assert_eq!(packet.weight.get(), 0.0);
+    assert_eq!(packet.checksum, Wrapping(0));
+    assert!(packet.on_close.is_none());
+    assert_eq!(packet.items, [0, 0]);
+}
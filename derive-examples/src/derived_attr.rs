@@ -0,0 +1,73 @@
+//! This module shows the unified `#[derived(...)]` attribute, along with the per-macro namespaces
+//!
+
+use derived::{Ctor, Gtor, Stor};
+use std::marker::PhantomData;
+
+#[derive(Clone, Copy, Ctor)]
+#[ctor(const)] // same as `#[ctor_const]`
+pub struct Color {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+#[derive(Ctor, Gtor, Stor)]
+#[derived(const)] // makes both the ctor and the getters const (but not the setters)
+pub struct Pixel<'a> {
+    #[derived(copy)] // same as `#[gtor_copy]`
+    color: Color,
+    #[derived(skip(set))] // same as `#[stor_skip]`
+    x: u16,
+    #[stor(skip)] // also the same as `#[stor_skip]`
+    y: u16,
+    #[derived(skip)] // no getters and no setters
+    z_index: u8,
+    #[derived(phantom)] // same as `#[phantom]`
+    _canvas: PhantomData<&'a ()>,
+}
+
+const PIXEL: Pixel = Pixel::new(Color::new(255, 255, 255), 1, 2, 0);
+const PIXEL_X: u16 = PIXEL.get_x();
+
+#[derive(Ctor, Gtor, Stor)]
+#[derived(const)] // fine with a `String`, since the setters aren't const
+pub struct Label {
+    name: String,
+    id: u64,
+}
+
+#[derive(Ctor, Gtor)]
+#[gtor(get_mut, no_get)] // only mutable getters
+pub struct Cursor {
+    position: u32,
+}
+
+#[derive(Ctor, Gtor)]
+#[gtor(get_mut)] // the immutable getters are still generated
+pub struct Counter {
+    count: u32,
+}
+
+#[test]
+fn test_derived_attr() {
+    let mut pixel = PIXEL;
+    assert_eq!(PIXEL_X, 1);
+    assert_eq!(pixel.get_y(), 2);
+    assert_eq!(pixel.get_color().r, 255);
+    pixel.set_color(Color::new(0, 0, 0));
+    assert_eq!(pixel.get_color().g, 0);
+    assert_eq!(pixel.get_color().b, 0);
+    assert_eq!(pixel.z_index, 0);
+    let mut label = Label::new("a".to_owned(), 0);
+    label.set_name("b".to_owned());
+    label.set_id(1);
+    assert_eq!(label.get_name(), "b");
+    assert_eq!(label.get_id(), 1);
+    let mut cursor = Cursor::new(0);
+    *cursor.get_position_mut() += 1;
+    assert_eq!(cursor.position, 1);
+    let mut counter = Counter::new(0);
+    *counter.get_count_mut() += 1;
+    assert_eq!(counter.get_count(), 1);
+}
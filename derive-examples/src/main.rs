@@ -1,16 +1,66 @@
 #[cfg(test)]
+#[allow(clippy::redundant_static_lifetimes)]
 mod basic;
 #[cfg(test)]
+mod cfg_fields;
+#[cfg(test)]
+mod collection_fields;
+#[cfg(test)]
+mod const_bounds;
+#[cfg(test)]
+#[allow(clippy::assertions_on_constants)]
 mod const_default;
 #[cfg(test)]
+mod const_default_arrays;
+#[cfg(test)]
+mod const_default_collections;
+#[cfg(test)]
+mod const_default_custom;
+#[cfg(test)]
+mod const_default_enum;
+#[cfg(test)]
+mod const_default_nested;
+#[cfg(test)]
+mod const_default_output;
+#[cfg(test)]
+mod const_default_sync;
+#[cfg(test)]
+mod const_default_tuple;
+#[cfg(test)]
+mod const_default_wrappers;
+#[cfg(test)]
+mod const_default_zeroed;
+#[cfg(test)]
 mod custom_copy;
 #[cfg(test)]
+mod derived_attr;
+#[cfg(test)]
 mod get_get_mut;
 #[cfg(test)]
 mod gtor_skip;
 #[cfg(test)]
+mod option_fields;
+#[cfg(test)]
 mod phantom;
 #[cfg(test)]
+mod raw_idents;
+#[cfg(test)]
+mod stor_chain;
+#[cfg(test)]
+mod stor_const;
+#[cfg(test)]
+mod stor_generic;
+#[cfg(test)]
+mod stor_hooks;
+#[cfg(test)]
+mod stor_into;
+#[cfg(test)]
+mod stor_replace;
+#[cfg(test)]
 mod stor_skip;
+#[cfg(test)]
+mod stor_validate;
+#[cfg(test)]
+mod stor_with;
 
 fn main() {}
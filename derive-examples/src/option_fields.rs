@@ -0,0 +1,49 @@
+//! This module shows the accessors for `Option` fields
+//!
+
+use derived::{Ctor, Gtor, Stor};
+
+#[derive(Ctor, Gtor, Stor)]
+#[gtor(get, get_mut, option)] // every `Option` field gets the gtors for options
+#[stor(option, chain)]
+pub struct Request {
+    path: String, // not an option, so this one gets the usual accessors
+    body: Option<Vec<u8>>,
+    timeout: core::option::Option<u64>,
+    #[stor(into)]
+    user_agent: Option<String>,
+}
+
+#[derive(Ctor, Gtor)]
+pub struct Flags {
+    #[gtor(option)] // only for this field
+    level: Option<u8>,
+    raw: Option<u8>,
+}
+
+#[test]
+fn test_option_fields() {
+    let mut request = Request::new("/".to_owned(), None, Some(30), None);
+    assert_eq!(request.get_path(), "/");
+    assert!(!request.has_body());
+    assert_eq!(request.get_body_or(&vec![]), &Vec::<u8>::new());
+    request
+        .set_body(vec![1, 2])
+        .set_user_agent("derived")
+        .clear_timeout();
+    assert_eq!(request.get_body(), Some(&vec![1, 2]));
+    assert_eq!(
+        request.get_user_agent().map(String::as_str),
+        Some("derived")
+    );
+    assert_eq!(request.get_timeout(), None);
+    assert_eq!(request.get_timeout_or(10), 10);
+    if let Some(body) = request.get_body_mut() {
+        body.push(3);
+    }
+    assert_eq!(request.take_body(), Some(vec![1, 2, 3]));
+    assert!(!request.has_body());
+    let flags = Flags::new(Some(1), None);
+    assert_eq!(flags.get_level(), Some(1));
+    assert_eq!(flags.get_raw(), &None);
+}
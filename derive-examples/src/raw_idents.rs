@@ -0,0 +1,24 @@
+//! This module shows how fields named with raw identifiers (like `r#type`) are handled
+//!
+
+use derived::{Constdef, Ctor, Gtor, Stor};
+
+#[derive(Constdef, Ctor, Gtor, Stor)]
+#[gtor(get, get_mut)]
+pub struct Token {
+    r#type: u8,
+    r#match: bool,
+}
+
+#[test]
+fn test_raw_idents() {
+    let mut token = Token::new(1, true);
+    // the `r#` prefix is dropped from the accessor names
+    assert_eq!(token.get_type(), 1);
+    assert!(token.get_match());
+    token.set_type(2);
+    *token.get_match_mut() = false;
+    assert_eq!(token.r#type, 2);
+    assert!(!token.r#match);
+    assert_eq!(Token::default().get_type(), 0);
+}
@@ -0,0 +1,37 @@
+//! This module shows how setters can be chained
+//!
+
+use derived::{Ctor, Gtor, Stor};
+
+#[derive(Ctor, Gtor, Stor)]
+#[stor(chain)] // all setters return `&mut Self`
+pub struct ServerConfig {
+    host: String,
+    port: u16,
+    tls: bool,
+    #[stor_skip] // skipped fields still don't get a setter
+    id: u64,
+}
+
+#[derive(Ctor, Stor)]
+pub struct Window {
+    #[stor(chain)] // only this setter returns `&mut Self`
+    width: u32,
+    height: u32,
+}
+
+#[test]
+fn test_stor_chain() {
+    let mut cfg = ServerConfig::new("localhost".to_owned(), 0, false, 1);
+    cfg.set_port(80)
+        .set_host("example.com".to_owned())
+        .set_tls(true);
+    assert_eq!(cfg.get_host(), "example.com");
+    assert_eq!(cfg.get_port(), 80);
+    assert!(cfg.get_tls());
+    assert_eq!(cfg.get_id(), 1);
+    let mut window = Window::new(0, 0);
+    window.set_width(640).set_height(480);
+    assert_eq!(window.width, 640);
+    assert_eq!(window.height, 480);
+}
@@ -0,0 +1,36 @@
+//! This module shows `const` stors, which can be used when building values at compile time
+//!
+
+use derived::{Ctor, Gtor, Stor};
+
+#[derive(Clone, Copy, Ctor, Gtor, Stor)]
+#[ctor_const]
+#[gtor_const]
+#[stor_const]
+pub struct Pixel {
+    rgb: (u8, u8, u8),
+    alpha: f32,
+    label: Option<&'static str>, // `Option<&T>` doesn't have drop glue, so this is fine too
+}
+
+const fn palette() -> [Pixel; 3] {
+    let mut palette = [Pixel::new((0, 0, 0), 1.0, None); 3];
+    palette[0].set_rgb((255, 0, 0));
+    palette[1].set_rgb((0, 255, 0));
+    palette[2].set_rgb((0, 0, 255));
+    palette[2].set_alpha(0.5);
+    palette[2].set_label(Some("blue"));
+    palette
+}
+
+const PALETTE: [Pixel; 3] = palette();
+const BLUE_ALPHA: f32 = PALETTE[2].get_alpha();
+
+#[test]
+fn test_stor_const() {
+    assert_eq!(PALETTE[0].get_rgb(), &(255, 0, 0));
+    assert_eq!(PALETTE[1].get_rgb(), &(0, 255, 0));
+    assert_eq!(BLUE_ALPHA, 0.5);
+    assert_eq!(PALETTE[0].get_label(), &None);
+    assert_eq!(PALETTE[2].get_label(), &Some("blue"));
+}
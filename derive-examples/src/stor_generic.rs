@@ -0,0 +1,29 @@
+//! This module shows setters that change the type of a generic field
+//!
+
+use derived::{Ctor, Gtor, Stor};
+use std::fmt::Display;
+
+#[derive(Ctor, Gtor, Stor)]
+#[stor(chain)]
+pub struct Labeled<'a, T, const N: usize>
+where
+    T: Display,
+{
+    #[stor(generic)] // `set_value<U: Display>` and `map_value<U: Display>`
+    value: T,
+    label: &'a str,
+    counts: [u8; N],
+}
+
+#[test]
+fn test_stor_generic() {
+    let mut labeled = Labeled::new(1u8, "one", [0; 2]);
+    labeled.set_label("uno").set_counts([1, 2]);
+    let labeled: Labeled<'_, f32, 2> = labeled.map_value(|v| v as f32 + 0.5);
+    assert_eq!(labeled.get_value(), &1.5);
+    let labeled = labeled.set_value("two");
+    assert_eq!(labeled.get_value(), &"two");
+    assert_eq!(labeled.get_label(), "uno");
+    assert_eq!(labeled.get_counts(), &[1, 2]);
+}
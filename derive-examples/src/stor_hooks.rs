@@ -0,0 +1,48 @@
+//! This module shows the change notification hooks on stors
+//!
+
+use derived::{Ctor, Gtor, Stor};
+use std::cell::RefCell;
+use std::fmt::Debug;
+
+#[derive(Ctor, Gtor, Stor)]
+#[stor(on_change = record, chain, replace)] // every stor calls `record`
+pub struct Settings {
+    theme: String,
+    #[stor(on_change_distinct = invalidate)] // only called when the value changes
+    font_size: u8,
+    #[stor_skip]
+    #[gtor_skip]
+    changes: RefCell<Vec<String>>,
+}
+
+impl Settings {
+    fn record<T: Debug>(&self, field: &str, old: &T, new: &T) {
+        self.changes
+            .borrow_mut()
+            .push(format!("{}: {:?} -> {:?}", field, old, new));
+    }
+    fn invalidate<T: Debug>(&self, field: &str, _old: &T, _new: &T) {
+        self.changes
+            .borrow_mut()
+            .push(format!("invalidated {}", field));
+    }
+}
+
+#[test]
+fn test_stor_hooks() {
+    let mut settings = Settings::new("light".to_owned(), 12, RefCell::new(vec![]));
+    settings
+        .set_theme("dark".to_owned())
+        .set_font_size(12)
+        .set_font_size(14);
+    assert_eq!(settings.replace_theme("solarized".to_owned()), "dark");
+    assert_eq!(
+        settings.changes.into_inner(),
+        vec![
+            r#"theme: "light" -> "dark""#,
+            "invalidated font_size",
+            r#"theme: "dark" -> "solarized""#,
+        ]
+    );
+}
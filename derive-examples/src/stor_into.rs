@@ -0,0 +1,39 @@
+//! This module shows setters that accept anything that converts into the field's type
+//!
+
+use derived::{Ctor, Gtor, Stor};
+use std::borrow::Cow;
+use std::path::PathBuf;
+
+#[derive(Ctor, Gtor, Stor)]
+#[stor(into, chain)] // `set_*(&mut self, x: impl Into<T>) -> &mut Self` for every field
+pub struct Profile {
+    name: String,
+    location: PathBuf,
+    score: u64,
+}
+
+#[derive(Ctor, Gtor, Stor)]
+pub struct Wrapper<T: Clone> {
+    #[stor(into, with)] // works with generics and `with_*` too
+    inner: Vec<T>,
+    #[stor(with)]
+    len: usize,
+}
+
+#[test]
+fn test_stor_into() {
+    let mut profile = Profile::new(String::new(), PathBuf::new(), 0);
+    profile
+        .set_name("sayan")
+        .set_location("/home/sayan")
+        .set_score(10u8);
+    assert_eq!(profile.get_name(), "sayan");
+    assert_eq!(profile.get_location(), &PathBuf::from("/home/sayan"));
+    assert_eq!(profile.get_score(), 10);
+    profile.set_name(Cow::Borrowed("ohsayan"));
+    assert_eq!(profile.get_name(), "ohsayan");
+    let wrapper = Wrapper::new(vec![], 0).with_inner([1, 2, 3]).with_len(3);
+    assert_eq!(wrapper.get_inner(), &vec![1, 2, 3]);
+    assert_eq!(wrapper.get_len(), 3);
+}
@@ -0,0 +1,49 @@
+//! This module shows the `replace_*`, `take_*` and `swap_*` methods
+//!
+
+use core::marker::PhantomData;
+use derived::{Ctor, Gtor, Stor};
+
+#[derive(Ctor, Gtor, Stor)]
+#[stor(replace, take, swap)]
+pub struct Queue<T> {
+    items: Vec<T>,
+    #[stor(skip)] // no stors at all for this one
+    name: String,
+    #[phantom]
+    _marker: PhantomData<T>,
+}
+
+#[derive(Ctor, Gtor, Stor)]
+pub struct Counter {
+    #[stor(replace, validate = check_count, error = String)] // `try_replace_count`
+    count: u32,
+    #[stor(take)]
+    label: Option<String>,
+}
+
+fn check_count(count: &u32) -> Result<(), String> {
+    if *count > 100 {
+        Err(format!("{} is too large", count))
+    } else {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_stor_replace() {
+    let mut queue = Queue::new(vec![1, 2], "jobs".to_owned());
+    assert_eq!(queue.replace_items(vec![3]), vec![1, 2]);
+    let mut other = vec![4, 5];
+    queue.swap_items(&mut other);
+    assert_eq!(other, vec![3]);
+    assert_eq!(queue.take_items(), vec![4, 5]);
+    assert!(queue.get_items().is_empty());
+    assert_eq!(queue.get_name(), "jobs");
+    let mut counter = Counter::new(1, Some("hits".to_owned()));
+    assert_eq!(counter.try_replace_count(2), Ok(1));
+    assert!(counter.try_replace_count(101).is_err());
+    assert_eq!(counter.get_count(), 2);
+    assert_eq!(counter.take_label().as_deref(), Some("hits"));
+    assert_eq!(counter.get_label(), &None);
+}
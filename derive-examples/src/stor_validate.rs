@@ -0,0 +1,56 @@
+//! This module shows validating and normalizing setters
+//!
+
+use derived::{Ctor, Gtor, Stor};
+
+#[derive(Debug, PartialEq)]
+pub enum PortError {
+    Reserved,
+}
+
+fn check_port(port: &u16) -> Result<(), PortError> {
+    if *port < 1024 {
+        Err(PortError::Reserved)
+    } else {
+        Ok(())
+    }
+}
+
+fn lowercase(host: String) -> String {
+    host.to_lowercase()
+}
+
+#[derive(Ctor, Gtor, Stor)]
+#[stor(chain, with)]
+pub struct Server {
+    #[stor(validate = check_port, error = PortError)] // `try_set_port` and `try_with_port`
+    port: u16,
+    #[stor(into, map = lowercase)] // converted first, then normalized
+    host: String,
+    #[stor(map = |workers: usize| workers.clamp(1, 64))]
+    workers: usize,
+}
+
+#[test]
+fn test_stor_validate() {
+    let mut server = Server::new(8080, "localhost".to_owned(), 1);
+    assert_eq!(server.try_set_port(80).err(), Some(PortError::Reserved));
+    assert_eq!(server.get_port(), 8080);
+    server
+        .try_set_port(2003)
+        .unwrap()
+        .set_host("EXAMPLE.com")
+        .set_workers(0);
+    assert_eq!(server.get_port(), 2003);
+    assert_eq!(server.get_host(), "example.com");
+    assert_eq!(server.get_workers(), 1);
+    let server = server.with_workers(100);
+    assert_eq!(server.get_workers(), 64);
+    assert_eq!(server.try_with_port(443).err(), Some(PortError::Reserved));
+    let server = Server::new(8080, String::new(), 1)
+        .try_with_port(9000)
+        .unwrap()
+        .with_host("Local");
+    assert_eq!(server.get_port(), 9000);
+    assert_eq!(server.get_host(), "local");
+}
@@ -0,0 +1,51 @@
+//! This module shows the by-value `with_*` methods, including the `const` ones
+//!
+
+use derived::{Constdef, Ctor, Gtor, Stor};
+
+#[derive(Constdef, Gtor, Stor)]
+#[stor(with(const))] // `const fn with_*` for every field
+pub struct Config {
+    port: u16,
+    retries: u8,
+    verbose: bool,
+    timeouts: (u32, u32),
+}
+
+const CFG: Config = Config::default().with_port(8080).with_retries(3);
+
+#[derive(Clone, Copy, Ctor)]
+#[ctor_const]
+pub struct Limits {
+    max_conns: u32,
+    max_body: u64,
+}
+
+#[derive(Ctor, Stor)]
+pub struct Greeting {
+    #[stor(with)] // a plain `fn with_message`, since `String` has drop glue
+    message: String,
+    #[stor(with(const))] // `Limits` is `Copy`, so it doesn't have drop glue
+    limits: Limits,
+    #[stor(with(const))]
+    timeout: Option<u32>,
+}
+
+#[test]
+fn test_stor_with() {
+    assert_eq!(CFG.get_port(), 8080);
+    assert_eq!(CFG.get_retries(), 3);
+    assert!(!CFG.get_verbose());
+    assert_eq!(CFG.get_timeouts(), &(0, 0));
+    let cfg = Config::default().with_verbose(true).with_timeouts((1, 2));
+    assert!(cfg.get_verbose());
+    assert_eq!(cfg.get_timeouts(), &(1, 2));
+    let greeting = Greeting::new("hi".to_owned(), Limits::new(0, 0), None)
+        .with_message("hello".to_owned())
+        .with_limits(Limits::new(10, 100))
+        .with_timeout(Some(30));
+    assert_eq!(greeting.message, "hello");
+    assert_eq!(greeting.limits.max_conns, 10);
+    assert_eq!(greeting.limits.max_body, 100);
+    assert_eq!(greeting.timeout, Some(30));
+}
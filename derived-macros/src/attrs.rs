@@ -0,0 +1,1071 @@
+//! This module parses the attributes understood by the macros into typed configuration
+//!
+//! Every macro understands the shared `#[derived(...)]` namespace, its own namespace (like
+//! `#[gtor(...)]`) and its legacy attributes (like `#[gtor_skip]`), which are just other
+//! spellings for keys in one of these namespaces
+//!
+
+use crate::diagnostics::Diagnostics;
+use crate::util;
+use ::quote::{
+    __private::{Span, TokenStream},
+    ToTokens,
+};
+use ::syn::{
+    ext::IdentExt,
+    parenthesized,
+    parse::{discouraged::Speculative, Parse, ParseStream},
+    punctuated::Punctuated,
+    spanned::Spanned,
+    Attribute, Data, DeriveInput, Expr, Ident, LitStr, Token, Type,
+};
+
+/// The namespace shared by all the macros
+const NS_DERIVED: &str = "derived";
+
+/// The macro that is reading the attributes
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum Derive {
+    Constdef,
+    Ctor,
+    Gtor,
+    Stor,
+}
+
+impl Derive {
+    const ALL: [Derive; 4] = [Derive::Constdef, Derive::Ctor, Derive::Gtor, Derive::Stor];
+    /// Returns the namespace owned by this macro
+    fn namespace(self) -> &'static str {
+        match self {
+            Derive::Constdef => "constdef",
+            Derive::Ctor => "ctor",
+            Derive::Gtor => "gtor",
+            Derive::Stor => "stor",
+        }
+    }
+    /// Returns the keys accepted in this macro's namespace
+    fn keys(self) -> &'static [Key] {
+        match self {
+            Derive::Constdef => CONSTDEF_KEYS,
+            Derive::Ctor => CTOR_KEYS,
+            Derive::Gtor => GTOR_KEYS,
+            Derive::Stor => STOR_KEYS,
+        }
+    }
+    /// Returns this macro's legacy attributes, along with the key in its namespace that they
+    /// stand for
+    fn legacy(self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Derive::Constdef => &[],
+            Derive::Ctor => &[("ctor_const", "const")],
+            Derive::Gtor => &[
+                ("gtor_const", "const"),
+                ("gtor_copy", "copy"),
+                ("gtor_skip", "skip"),
+            ],
+            Derive::Stor => &[("stor_const", "const"), ("stor_skip", "skip")],
+        }
+    }
+    /// Returns true if `attr` is in this macro's namespace, or is one of its legacy attributes
+    fn owns(self, attr: &Attribute) -> bool {
+        attr.path.is_ident(self.namespace())
+            || self
+                .legacy()
+                .iter()
+                .any(|(legacy, _)| attr.path.is_ident(legacy))
+    }
+    /// Returns the name used for this macro in `#[derived(skip(...))]`
+    fn skip_name(self) -> Option<&'static str> {
+        match self {
+            Derive::Constdef | Derive::Ctor => None,
+            Derive::Gtor => Some("get"),
+            Derive::Stor => Some("set"),
+        }
+    }
+}
+
+/// Where a key can be used
+#[derive(Clone, Copy, PartialEq)]
+enum Target {
+    Struct,
+    Field,
+    /// On an enum variant
+    Variant,
+    /// On the struct (applying to all fields) or on specific fields
+    Anywhere,
+}
+
+impl Target {
+    fn allows(self, target: Target) -> bool {
+        self == target || (self == Target::Anywhere && target != Target::Variant)
+    }
+}
+
+/// How a key can be written
+#[derive(Clone, Copy)]
+enum Kind {
+    /// `key`
+    Flag,
+    /// `key` or `key(sub, keys)`, with the given sub-keys
+    FlagOrList(&'static [&'static str]),
+    /// `key = expr`
+    Expr,
+    /// `key = Type`
+    Type,
+    /// `key = "name"`, where the name is used in the names of the generated items
+    Name,
+    /// `key = method`
+    Method,
+}
+
+/// A key accepted by a namespace
+struct Key {
+    name: &'static str,
+    target: Target,
+    kind: Kind,
+}
+
+impl Key {
+    const fn new(name: &'static str, target: Target, kind: Kind) -> Self {
+        Self { name, target, kind }
+    }
+}
+
+const DERIVED_KEYS: &[Key] = &[
+    Key::new("const", Target::Struct, Kind::Flag),
+    Key::new("copy", Target::Field, Kind::Flag),
+    Key::new("phantom", Target::Field, Kind::Flag),
+    Key::new("skip", Target::Field, Kind::FlagOrList(&["get", "set"])),
+];
+
+const CONSTDEF_KEYS: &[Key] = &[
+    Key::new("assoc_const", Target::Struct, Kind::Flag),
+    Key::new("const_fn", Target::Struct, Kind::Method),
+    Key::new("default", Target::Field, Kind::Expr),
+    Key::new("default", Target::Variant, Kind::Flag),
+    Key::new("no_const_fn", Target::Struct, Kind::Flag),
+    Key::new("no_default_impl", Target::Struct, Kind::Flag),
+    Key::new("zeroed", Target::Struct, Kind::Flag),
+];
+
+const CTOR_KEYS: &[Key] = &[Key::new("const", Target::Struct, Kind::Flag)];
+
+const GTOR_KEYS: &[Key] = &[
+    Key::new("const", Target::Struct, Kind::Flag),
+    Key::new("get", Target::Struct, Kind::Flag),
+    Key::new("get_mut", Target::Struct, Kind::Flag),
+    Key::new("no_get", Target::Struct, Kind::Flag),
+    Key::new("collection", Target::Anywhere, Kind::Flag),
+    Key::new("copy", Target::Field, Kind::Flag),
+    Key::new("option", Target::Anywhere, Kind::Flag),
+    Key::new("skip", Target::Field, Kind::Flag),
+];
+
+const STOR_KEYS: &[Key] = &[
+    Key::new("const", Target::Struct, Kind::Flag),
+    Key::new("chain", Target::Anywhere, Kind::Flag),
+    Key::new("collection", Target::Anywhere, Kind::Flag),
+    Key::new("each", Target::Field, Kind::Name),
+    Key::new("error", Target::Field, Kind::Type),
+    Key::new("generic", Target::Field, Kind::Flag),
+    Key::new("into", Target::Anywhere, Kind::Flag),
+    Key::new("map", Target::Field, Kind::Expr),
+    Key::new("on_change", Target::Anywhere, Kind::Method),
+    Key::new("on_change_distinct", Target::Anywhere, Kind::Method),
+    Key::new("option", Target::Anywhere, Kind::Flag),
+    Key::new("replace", Target::Anywhere, Kind::Flag),
+    Key::new("skip", Target::Field, Kind::Flag),
+    Key::new("swap", Target::Anywhere, Kind::Flag),
+    Key::new("take", Target::Anywhere, Kind::Flag),
+    Key::new("validate", Target::Field, Kind::Expr),
+    Key::new("with", Target::Anywhere, Kind::FlagOrList(&["const"])),
+];
+
+/// A single item in an attribute: `key`, `key = value` or `key(items, ...)`
+pub(crate) struct AttrItem {
+    pub(crate) key: Ident,
+    pub(crate) value: AttrValue,
+}
+
+pub(crate) enum AttrValue {
+    Flag,
+    /// The tokens for the value, which are parsed once we know what the key expects
+    Value(TokenStream),
+    List(Vec<AttrItem>),
+}
+
+impl Parse for AttrItem {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        // allow keywords like `const` to be used as keys
+        let key = input.call(Ident::parse_any)?;
+        let value = if input.peek(Token![=]) {
+            input.parse::<Token![=]>()?;
+            AttrValue::Value(self::parse_value(input)?)
+        } else if input.peek(syn::token::Paren) {
+            let content;
+            parenthesized!(content in input);
+            AttrValue::List(self::parse_items(&content)?)
+        } else {
+            AttrValue::Flag
+        };
+        Ok(Self { key, value })
+    }
+}
+
+/// Parses the value after `key =`. A type like `Result<u8, String>` has a comma that isn't within a
+/// group, so types are tried first. Otherwise, we wouldn't know where the value ends
+fn parse_value(input: ParseStream) -> syn::Result<TokenStream> {
+    let fork = input.fork();
+    if fork.parse::<Type>().is_ok() && (fork.is_empty() || fork.peek(Token![,])) {
+        let ty: Type = input.parse()?;
+        return Ok(ty.into_token_stream());
+    }
+    let fork = input.fork();
+    let expr: Expr = fork.parse()?;
+    input.advance_to(&fork);
+    Ok(expr.into_token_stream())
+}
+
+fn parse_items(input: ParseStream) -> syn::Result<Vec<AttrItem>> {
+    Ok(Punctuated::<AttrItem, Token![,]>::parse_terminated(input)?
+        .into_iter()
+        .collect())
+}
+
+impl AttrItem {
+    fn name(&self) -> String {
+        self.key.unraw().to_string()
+    }
+    fn span(&self) -> Span {
+        self.key.span()
+    }
+}
+
+/// Which namespace a key was found in
+#[derive(Clone, Copy, PartialEq)]
+enum Namespace {
+    /// `#[derived(...)]`
+    Shared,
+    /// The macro's own namespace
+    Own,
+    /// `#[phantom]`, which is shared by all the macros like `#[derived(...)]` but is spelled on its
+    /// own, just like the other legacy attributes
+    Phantom,
+}
+
+/// A validated key, as found in one of the attributes
+struct Entry {
+    namespace: Namespace,
+    item: AttrItem,
+    /// How the key was written, for diagnostics
+    written: String,
+}
+
+impl Entry {
+    fn is(&self, namespace: Namespace, key: &str) -> bool {
+        self.namespace == namespace && self.item.name() == key
+    }
+}
+
+/// Collects and validates all the keys for `derive` from `attrs`, adding errors for the invalid
+/// ones to `diag`
+fn collect(
+    attrs: &[Attribute],
+    derive: Derive,
+    target: Target,
+    diag: &mut Diagnostics,
+) -> Vec<Entry> {
+    let mut ret: Vec<Entry> = Vec::new();
+    for attr in attrs {
+        let attr_name = attr.path.to_token_stream().to_string();
+        let (namespace, keys, items, legacy) = if attr.path.is_ident(NS_DERIVED) {
+            let items = diag.ok(attr.parse_args_with(self::parse_items));
+            (Namespace::Shared, DERIVED_KEYS, items, false)
+        } else if attr.path.is_ident(derive.namespace()) {
+            let items = diag.ok(attr.parse_args_with(self::parse_items));
+            (Namespace::Own, derive.keys(), items, false)
+        } else if let Some((namespace, keys, key)) = self::legacy_key(attr, derive) {
+            if !attr.tokens.is_empty() {
+                diag.push(syn::Error::new_spanned(
+                    &attr.tokens,
+                    format!("`#[{}]` doesn't accept any arguments", attr_name),
+                ));
+            }
+            let item = AttrItem {
+                key: Ident::new(key, attr.path.span()),
+                value: AttrValue::Flag,
+            };
+            (namespace, keys, Some(vec![item]), true)
+        } else {
+            continue;
+        };
+        for item in items.into_iter().flatten() {
+            let written = if legacy {
+                format!("`#[{}]`", attr_name)
+            } else {
+                format!("`{}` in `#[{}(...)]`", item.name(), attr_name)
+            };
+            if !self::validate(&item, keys, target, &written, diag) {
+                continue;
+            }
+            let entry = Entry {
+                namespace,
+                item,
+                written,
+            };
+            match ret
+                .iter()
+                .find(|e| e.is(entry.namespace, &entry.item.name()))
+            {
+                Some(first) => diag.error(
+                    entry.item.span(),
+                    format!(
+                        "Found duplicate attributes for {} (first set by {})",
+                        entry.written, first.written
+                    ),
+                ),
+                None => ret.push(entry),
+            }
+        }
+    }
+    ret
+}
+
+/// If `attr` is a legacy attribute for `derive`, returns where its key lives
+fn legacy_key(
+    attr: &Attribute,
+    derive: Derive,
+) -> Option<(Namespace, &'static [Key], &'static str)> {
+    if attr.path.is_ident("phantom") {
+        return Some((Namespace::Phantom, DERIVED_KEYS, "phantom"));
+    }
+    derive
+        .legacy()
+        .iter()
+        .find(|(legacy, _)| attr.path.is_ident(legacy))
+        .map(|(_, key)| (Namespace::Own, derive.keys(), *key))
+}
+
+/// Returns a list of names, in a form suitable for help text
+fn list_names<'a>(names: impl Iterator<Item = &'a str>) -> String {
+    names
+        .map(|name| format!("`{}`", name))
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+/// Checks that `item` is valid for `target`, adding errors to `diag` if it isn't
+fn validate(
+    item: &AttrItem,
+    keys: &[Key],
+    target: Target,
+    written: &str,
+    diag: &mut Diagnostics,
+) -> bool {
+    let name = item.name();
+    let valid_here = || {
+        keys.iter()
+            .filter(|k| k.target.allows(target))
+            .map(|k| k.name)
+    };
+    // a key can mean different things on different targets (like `default`), so the one for this
+    // target is preferred
+    let mut named = keys.iter().filter(|key| key.name == name);
+    let key = match named
+        .clone()
+        .find(|key| key.target.allows(target))
+        .or_else(|| named.next())
+    {
+        Some(key) => key,
+        None => {
+            let mut msg = format!("Unknown key {}", written);
+            // only the keys that can be used here are suggested
+            if let Some(suggestion) = util::did_you_mean(&name, valid_here()) {
+                msg.push_str(&format!(". Did you mean `{}`?", suggestion));
+            }
+            let mut supported: Vec<&str> = valid_here().collect();
+            supported.dedup();
+            let supported = self::list_names(supported.into_iter());
+            if !supported.is_empty() {
+                msg.push_str(&format!(
+                    "\nhelp: the supported keys here are: {}",
+                    supported
+                ));
+            }
+            diag.error(item.span(), msg);
+            return false;
+        }
+    };
+    if !key.target.allows(target) {
+        let on = match target {
+            Target::Struct => "the entire struct",
+            Target::Field => "a field",
+            Target::Variant => "an enum variant",
+            Target::Anywhere => unreachable!("attributes are always read from a specific target"),
+        };
+        let only = match key.target {
+            Target::Struct => "the entire struct",
+            Target::Field => "fields",
+            Target::Variant => "enum variants",
+            Target::Anywhere => "the entire struct or on fields",
+        };
+        diag.error(
+            item.span(),
+            format!(
+                "Marking sub-attribute {} on {} is invalid! It can only be used on {}",
+                written, on, only
+            ),
+        );
+        return false;
+    }
+    match (&item.value, key.kind) {
+        (AttrValue::Flag, Kind::Flag | Kind::FlagOrList(_)) => true,
+        (
+            AttrValue::Flag | AttrValue::List(_),
+            Kind::Expr | Kind::Type | Kind::Name | Kind::Method,
+        ) => {
+            let example = match key.kind {
+                Kind::Expr if name == "default" => "value",
+                Kind::Expr => "path::to::function",
+                Kind::Type => "Type",
+                Kind::Method => "method",
+                _ => "\"name\"",
+            };
+            diag.error(
+                item.span(),
+                format!(
+                    "{} needs a value\nhelp: write it like `{} = {}`",
+                    written, name, example
+                ),
+            );
+            false
+        }
+        (AttrValue::Value(value), Kind::Expr) => {
+            self::check_parses::<Expr>(value, written, "an expression", diag)
+        }
+        (AttrValue::Value(value), Kind::Type) => {
+            self::check_parses::<Type>(value, written, "a type", diag)
+        }
+        (AttrValue::Value(value), Kind::Method) => {
+            self::check_parses::<Ident>(value, written, "the name of a method", diag)
+        }
+        (AttrValue::Value(value), Kind::Name) => {
+            // the name is used to build identifiers, so it has to be a valid one
+            let is_valid = syn::parse2::<LitStr>(value.clone())
+                .map(|lit| syn::parse_str::<Ident>(&lit.value()).is_ok())
+                .unwrap_or(false);
+            if !is_valid {
+                diag.push(syn::Error::new_spanned(
+                    value,
+                    format!(
+                        "{} expects a name in quotes, like `{} = \"item\"`",
+                        written, name
+                    ),
+                ));
+            }
+            is_valid
+        }
+        (AttrValue::List(list), Kind::FlagOrList(subkeys)) => {
+            let mut valid = true;
+            let mut seen: Vec<&AttrItem> = Vec::new();
+            for sub in list {
+                let sub_name = sub.name();
+                if !subkeys.contains(&sub_name.as_str()) {
+                    let mut msg = format!("Unknown value `{}` for {}", sub_name, written);
+                    if let Some(suggestion) = util::did_you_mean(&sub_name, subkeys.iter().copied())
+                    {
+                        msg.push_str(&format!(". Did you mean `{}`?", suggestion));
+                    }
+                    msg.push_str(&format!(
+                        "\nhelp: the supported values are: {}",
+                        self::list_names(subkeys.iter().copied())
+                    ));
+                    diag.error(sub.span(), msg);
+                    valid = false;
+                } else if !matches!(sub.value, AttrValue::Flag) {
+                    diag.error(
+                        sub.span(),
+                        format!("`{}` doesn't accept any arguments", sub_name),
+                    );
+                    valid = false;
+                } else if seen.iter().any(|s| s.name() == sub_name) {
+                    diag.error(
+                        sub.span(),
+                        format!("Found duplicate values for `{}`", sub_name),
+                    );
+                    valid = false;
+                } else {
+                    seen.push(sub);
+                }
+            }
+            valid
+        }
+        (AttrValue::Value(value), _) => {
+            diag.push(syn::Error::new_spanned(
+                value,
+                format!("{} doesn't accept a value", written),
+            ));
+            false
+        }
+        (AttrValue::List(_), Kind::Flag) => {
+            diag.error(
+                item.span(),
+                format!("{} doesn't accept any arguments", written),
+            );
+            false
+        }
+    }
+}
+
+/// Checks that `value` can be parsed as a `T`, adding an error to `diag` if it can't
+fn check_parses<T: Parse>(
+    value: &TokenStream,
+    written: &str,
+    expected: &str,
+    diag: &mut Diagnostics,
+) -> bool {
+    match syn::parse2::<T>(value.clone()) {
+        Ok(_) => true,
+        Err(_) => {
+            diag.push(syn::Error::new_spanned(
+                value,
+                format!("{} expects {}", written, expected),
+            ));
+            false
+        }
+    }
+}
+
+/// Returns the span of the first entry matching any of `keys`. Since all of them have the same
+/// effect, the others are redundant and we warn about them. The entries are taken in the order of
+/// `keys`, so that the shared ones come first: a shared entry also affects the other macros, so
+/// only the entries in this macro's own namespace are ever redundant
+fn find(entries: &[Entry], keys: &[(Namespace, &str)], diag: &mut Diagnostics) -> Option<Span> {
+    self::first_of(
+        keys.iter()
+            .flat_map(|(namespace, key)| entries.iter().filter(move |e| e.is(*namespace, key)))
+            .map(|e| (e.item.span(), e.written.clone())),
+        diag,
+    )
+}
+
+/// Returns the span of `sub` in the `key(...)` entry, if it is listed there
+fn find_sub(entries: &[Entry], (namespace, key): (Namespace, &str), sub: &str) -> Option<Span> {
+    entries
+        .iter()
+        .filter(|e| e.is(namespace, key))
+        .find_map(|e| match &e.item.value {
+            AttrValue::List(list) => list.iter().find(|s| s.name() == sub).map(|s| s.span()),
+            _ => None,
+        })
+}
+
+/// Returns the span of `key` along with its value parsed as a `T`, if it was set. The value has
+/// already been validated by [`collect`], so it always parses
+fn find_value<T: Parse>(
+    entries: &[Entry],
+    (namespace, key): (Namespace, &str),
+) -> Option<(Span, T)> {
+    entries
+        .iter()
+        .find(|e| e.is(namespace, key))
+        .and_then(|e| match &e.item.value {
+            AttrValue::Value(value) => syn::parse2(value.clone())
+                .ok()
+                .map(|value| (e.item.span(), value)),
+            _ => None,
+        })
+}
+
+/// Returns the span of the first of `spellings`, warning about the redundant others
+fn first_of(
+    mut spellings: impl Iterator<Item = (Span, String)>,
+    diag: &mut Diagnostics,
+) -> Option<Span> {
+    let (span, written) = spellings.next()?;
+    for (other_span, other) in spellings {
+        diag.warning(
+            other_span,
+            format!(
+                "{} is redundant, because {} has the same effect",
+                other, written
+            ),
+        );
+    }
+    Some(span)
+}
+
+/// The configuration for the struct as a whole. Each option holds the span of the key that set
+/// it, if it was set
+pub(crate) struct StructConfig {
+    /// Whether the generated functions should be `const`
+    pub(crate) is_const: Option<Span>,
+    /// Whether immutable getters should be generated (`Gtor` only)
+    pub(crate) get: Option<Span>,
+    /// Whether mutable getters should be generated (`Gtor` only)
+    pub(crate) get_mut: Option<Span>,
+    /// Whether immutable getters should be left out, so that only mutable ones are generated
+    /// (`Gtor` only)
+    pub(crate) no_get: Option<Span>,
+    /// Whether `Option` fields should get the accessors for options (`Gtor` and `Stor`)
+    pub(crate) option: Option<Span>,
+    /// Whether collection fields should get the accessors for collections (`Gtor` and `Stor`)
+    pub(crate) collection: Option<Span>,
+    /// Whether setters should return `&mut Self` (`Stor` only)
+    pub(crate) chain: Option<Span>,
+    /// Whether setters should accept anything that converts into the field's type (`Stor` only)
+    pub(crate) into: Option<Span>,
+    /// Whether `replace_*` methods should be generated (`Stor` only)
+    pub(crate) replace: Option<Span>,
+    /// Whether `take_*` methods should be generated (`Stor` only)
+    pub(crate) take: Option<Span>,
+    /// Whether `swap_*` methods should be generated (`Stor` only)
+    pub(crate) swap: Option<Span>,
+    /// Whether by-value `with_*` methods should be generated (`Stor` only)
+    pub(crate) with: Option<Span>,
+    /// Whether the `with_*` methods should be `const` (`Stor` only)
+    pub(crate) with_const: Option<Span>,
+    /// The method called after a value is assigned (`Stor` only)
+    pub(crate) on_change: Option<(Span, Ident)>,
+    /// The method called after a value is assigned, if it's different from the old one (`Stor`
+    /// only)
+    pub(crate) on_change_distinct: Option<(Span, Ident)>,
+    /// Whether a `DEFAULT` associated constant should be generated (`Constdef` only)
+    pub(crate) assoc_const: Option<Span>,
+    /// The name of the generated `const fn`, instead of `default` (`Constdef` only)
+    pub(crate) const_fn: Option<(Span, Ident)>,
+    /// Whether the `const fn` shouldn't be generated (`Constdef` only)
+    pub(crate) no_const_fn: Option<Span>,
+    /// Whether the `Default` impl shouldn't be generated (`Constdef` only)
+    pub(crate) no_default_impl: Option<Span>,
+    /// Whether the default should be all zeroes (`Constdef` only)
+    pub(crate) zeroed: Option<Span>,
+    /// The macro reading the attributes
+    derive: Derive,
+    /// The other macros that are known to be derived along with this one, since their own
+    /// attributes are used somewhere on the item
+    others: Vec<Derive>,
+}
+
+impl StructConfig {
+    pub(crate) fn parse(ast: &DeriveInput, derive: Derive, diag: &mut Diagnostics) -> Self {
+        let entries = self::collect(&ast.attrs, derive, Target::Struct, diag);
+        let mut ret = Self {
+            // setters can't always be `const` (unlike ctors and getters), so they have to be asked
+            // for with `#[stor_const]`, and `#[derived(const)]` doesn't make them `const`
+            is_const: match derive {
+                Derive::Stor => self::find(&entries, &[(Namespace::Own, "const")], diag),
+                _ => self::find(
+                    &entries,
+                    &[(Namespace::Shared, "const"), (Namespace::Own, "const")],
+                    diag,
+                ),
+            },
+            get: self::find(&entries, &[(Namespace::Own, "get")], diag),
+            get_mut: self::find(&entries, &[(Namespace::Own, "get_mut")], diag),
+            no_get: self::find(&entries, &[(Namespace::Own, "no_get")], diag),
+            option: self::find(&entries, &[(Namespace::Own, "option")], diag),
+            collection: self::find(&entries, &[(Namespace::Own, "collection")], diag),
+            chain: self::find(&entries, &[(Namespace::Own, "chain")], diag),
+            into: self::find(&entries, &[(Namespace::Own, "into")], diag),
+            replace: self::find(&entries, &[(Namespace::Own, "replace")], diag),
+            take: self::find(&entries, &[(Namespace::Own, "take")], diag),
+            swap: self::find(&entries, &[(Namespace::Own, "swap")], diag),
+            with: self::find(&entries, &[(Namespace::Own, "with")], diag),
+            with_const: self::find_sub(&entries, (Namespace::Own, "with"), "const"),
+            on_change: self::find_value(&entries, (Namespace::Own, "on_change")),
+            on_change_distinct: self::find_value(&entries, (Namespace::Own, "on_change_distinct")),
+            assoc_const: self::find(&entries, &[(Namespace::Own, "assoc_const")], diag),
+            const_fn: self::find_value(&entries, (Namespace::Own, "const_fn")),
+            no_const_fn: self::find(&entries, &[(Namespace::Own, "no_const_fn")], diag),
+            no_default_impl: self::find(&entries, &[(Namespace::Own, "no_default_impl")], diag),
+            zeroed: self::find(&entries, &[(Namespace::Own, "zeroed")], diag),
+            derive,
+            others: self::derived_along(ast, derive),
+        };
+        if let (Some((const_fn, _)), Some(_)) = (&ret.const_fn, ret.no_const_fn) {
+            diag.error(
+                *const_fn,
+                "`const_fn` can't be used along with `no_const_fn`",
+            );
+        }
+        // immutable getters are always generated, unless they're turned off with `no_get`
+        match (ret.get, ret.get_mut, ret.no_get) {
+            (None, _, None) => ret.get = Some(Span::call_site()),
+            (Some(get), None, None) => {
+                diag.warning(
+                    get,
+                    "`get` is redundant, because immutable getters are generated by default",
+                );
+            }
+            (Some(get), _, Some(_)) => {
+                diag.error(get, "`get` can't be used along with `no_get`");
+            }
+            (None, None, Some(no_get)) => diag.error(
+                no_get,
+                "`no_get` would leave no getters to generate\nhelp: add `get_mut` to only generate mutable getters",
+            ),
+            (None, Some(_), Some(_)) => {
+                // `#[derived(const)]` still makes the other macros const, but our own `const`
+                // doesn't do anything
+                if let Some(own_const) = entries.iter().find(|e| e.is(Namespace::Own, "const")) {
+                    diag.warning(
+                        own_const.item.span(),
+                        format!(
+                            "{} has no effect, because only mutable getters are generated and they can't be `const`",
+                            own_const.written
+                        ),
+                    );
+                }
+            }
+            (Some(_), Some(_), None) => {}
+        }
+        ret
+    }
+    /// Returns true if this macro should warn about a shared attribute, which all of `readers`
+    /// read. Each of them would warn about it otherwise, so only the first one that is known to be
+    /// derived does
+    fn warns_for(&self, readers: &[Derive]) -> bool {
+        readers
+            .iter()
+            .find(|reader| **reader == self.derive || self.others.contains(reader))
+            == Some(&self.derive)
+    }
+}
+
+/// Returns the macros other than `derive` whose own attributes are used anywhere on `ast`. The
+/// macros can't see which others are derived along with them, so this is the best guess
+fn derived_along(ast: &DeriveInput, derive: Derive) -> Vec<Derive> {
+    let mut attrs: Vec<&Attribute> = ast.attrs.iter().collect();
+    match &ast.data {
+        Data::Struct(data) => attrs.extend(data.fields.iter().flat_map(|f| &f.attrs)),
+        Data::Enum(data) => {
+            for variant in data.variants.iter() {
+                attrs.extend(&variant.attrs);
+                attrs.extend(variant.fields.iter().flat_map(|f| &f.attrs));
+            }
+        }
+        Data::Union(data) => attrs.extend(data.fields.named.iter().flat_map(|f| &f.attrs)),
+    }
+    Derive::ALL
+        .iter()
+        .copied()
+        .filter(|other| *other != derive && attrs.iter().any(|attr| other.owns(attr)))
+        .collect()
+}
+
+/// The configuration for an enum variant (`Constdef` only)
+pub(crate) struct VariantConfig {
+    /// Whether this is the variant used as the default
+    pub(crate) default: Option<Span>,
+}
+
+impl VariantConfig {
+    pub(crate) fn parse(attrs: &[Attribute], derive: Derive, diag: &mut Diagnostics) -> Self {
+        let entries = self::collect(attrs, derive, Target::Variant, diag);
+        Self {
+            default: self::find(&entries, &[(Namespace::Own, "default")], diag),
+        }
+    }
+}
+
+/// Returns `detected` if the accessors for a kind of type (like options) were asked for, on the
+/// field or on the struct (`own`, `parent`). On the struct, they only apply to the fields that are
+/// of that kind, but a field that asks for them on its own has to be one
+fn detect<T>(
+    (own, parent): (Option<Span>, Option<Span>),
+    detected: Option<T>,
+    (key, kind, plural, help): (&str, &str, &str, Option<String>),
+    field: &Ident,
+    ty: &Type,
+    diag: &mut Diagnostics,
+) -> Option<T> {
+    match (own, &detected, parent) {
+        (Some(_), None, _) => {
+            let mut msg = format!(
+                "The `{}` field isn't {}, so it can't get the accessors for {}",
+                util::get_field_name(field),
+                kind,
+                plural
+            );
+            if let Some(help) = help {
+                msg.push_str(&format!("\nhelp: {}", help));
+            }
+            diag.error(ty.span(), msg);
+        }
+        (Some(own), Some(_), Some(_)) => diag.warning(
+            own,
+            format!(
+                "`{}` is redundant, because it is already set for the entire struct",
+                key
+            ),
+        ),
+        (None, _, None) => return None,
+        _ => {}
+    }
+    detected
+}
+
+/// The configuration for a single field. Each option holds the span of the key that set it, if
+/// it was set
+pub(crate) struct FieldConfig {
+    /// Whether the field is a `PhantomData` field
+    pub(crate) phantom: Option<Span>,
+    /// Whether the macro should skip the field
+    pub(crate) skip: Option<Span>,
+    /// Whether the field's type is `Copy` (`Gtor` only)
+    pub(crate) copy: Option<Span>,
+    /// Whether the field should get the accessors for options (`Gtor` and `Stor`). This isn't
+    /// inherited from the struct, see [`FieldConfig::option_type`]
+    pub(crate) option: Option<Span>,
+    /// Whether the field should get the accessors for collections (`Gtor` and `Stor`). This
+    /// isn't inherited from the struct, see [`FieldConfig::collection_type`]
+    pub(crate) collection: Option<Span>,
+    /// The name for a single element of the collection (`Stor` only)
+    pub(crate) each: Option<(Span, String)>,
+    /// Whether the setter should return `&mut Self` (`Stor` only)
+    pub(crate) chain: Option<Span>,
+    /// Whether the setter should accept anything that converts into the field's type (`Stor`
+    /// only)
+    pub(crate) into: Option<Span>,
+    /// Whether a `replace_*` method should be generated (`Stor` only)
+    pub(crate) replace: Option<Span>,
+    /// Whether a `take_*` method should be generated (`Stor` only)
+    pub(crate) take: Option<Span>,
+    /// Whether a `swap_*` method should be generated (`Stor` only)
+    pub(crate) swap: Option<Span>,
+    /// Whether a by-value `with_*` method should be generated (`Stor` only)
+    pub(crate) with: Option<Span>,
+    /// Whether the `with_*` method should be `const` (`Stor` only)
+    pub(crate) with_const: Option<Span>,
+    /// The function that normalizes values before they're assigned (`Stor` only)
+    pub(crate) map: Option<(Span, Expr)>,
+    /// The function that validates values before they're assigned (`Stor` only)
+    pub(crate) validate: Option<(Span, Expr)>,
+    /// The error type returned when validation fails (`Stor` only)
+    pub(crate) error: Option<(Span, Type)>,
+    /// Whether the setter should be able to change the type of the field, and hence the struct
+    /// (`Stor` only)
+    pub(crate) generic: Option<Span>,
+    /// The method called after a value is assigned (`Stor` only). A hook set on a field replaces
+    /// the ones set for the struct
+    pub(crate) on_change: Option<(Span, Ident)>,
+    /// The method called after a value is assigned, if it's different from the old one (`Stor`
+    /// only)
+    pub(crate) on_change_distinct: Option<(Span, Ident)>,
+    /// The expression used for the field's default value (`Constdef` only)
+    pub(crate) default: Option<(Span, Expr)>,
+}
+
+impl FieldConfig {
+    /// Returns the `T` in the field's `Option<T>` type, if it should get the accessors for
+    /// options
+    pub(crate) fn option_type<'a>(
+        &self,
+        parent: &StructConfig,
+        field: &Ident,
+        ty: &'a Type,
+        diag: &mut Diagnostics,
+    ) -> Option<&'a Type> {
+        self::detect(
+            (self.option, parent.option),
+            util::get_option_inner(ty),
+            ("option", "an `Option`", "options", None),
+            field,
+            ty,
+            diag,
+        )
+    }
+    /// Returns the kind of collection the field is, if it should get the accessors for
+    /// collections
+    pub(crate) fn collection_type<'a>(
+        &self,
+        parent: &StructConfig,
+        field: &Ident,
+        ty: &'a Type,
+        diag: &mut Diagnostics,
+    ) -> Option<util::Collection<'a>> {
+        let collection = self::detect(
+            (self.collection, parent.collection),
+            util::get_collection(ty),
+            (
+                "collection",
+                "a collection",
+                "collections",
+                Some(format!(
+                    "the supported collections are {}",
+                    util::SUPPORTED_COLLECTIONS
+                )),
+            ),
+            field,
+            ty,
+            diag,
+        );
+        if let (Some((each, _)), None) = (&self.each, &collection) {
+            diag.warning(
+                *each,
+                "`each` has no effect, because the field doesn't get the accessors for collections",
+            );
+        }
+        collection
+    }
+    /// Parses the configuration for a field. Options that can also be set on the struct are
+    /// inherited from `parent`
+    pub(crate) fn parse(
+        attrs: &[Attribute],
+        derive: Derive,
+        parent: &StructConfig,
+        diag: &mut Diagnostics,
+    ) -> Self {
+        let entries = self::collect(attrs, derive, Target::Field, diag);
+        let mut inherit = |key: &str, from_parent: Option<Span>| {
+            let own = self::find(&entries, &[(Namespace::Own, key)], diag);
+            match (own, from_parent) {
+                (Some(own), Some(_)) => {
+                    diag.warning(
+                        own,
+                        format!(
+                            "`{}` is redundant, because it is already set for the entire struct",
+                            key
+                        ),
+                    );
+                    Some(own)
+                }
+                (own, from_parent) => own.or(from_parent),
+            }
+        };
+        let chain = inherit("chain", parent.chain);
+        let into = inherit("into", parent.into);
+        let replace = inherit("replace", parent.replace);
+        let take = inherit("take", parent.take);
+        let swap = inherit("swap", parent.swap);
+        // a plain `with` on the struct can be made `const` for specific fields, so this one is
+        // inherited by hand
+        let own_with = self::find(&entries, &[(Namespace::Own, "with")], diag);
+        let own_with_const = self::find_sub(&entries, (Namespace::Own, "with"), "const");
+        if let (Some(own_with), Some(_)) = (own_with, parent.with) {
+            if own_with_const.is_none() || parent.with_const.is_some() {
+                diag.warning(
+                    own_with,
+                    "`with` is redundant, because it is already set for the entire struct",
+                );
+            }
+        }
+        let with = own_with.or(parent.with);
+        let with_const = own_with_const.or(parent.with_const);
+        // `skip` skips the field for every macro, while `skip(get, set)` only skips it for
+        // the ones listed. Like in `find`, the shared entries come first
+        let shared_skips = entries
+            .iter()
+            .filter(|e| e.is(Namespace::Shared, "skip"))
+            .filter_map(|e| {
+                let skip_name = derive.skip_name()?;
+                match &e.item.value {
+                    AttrValue::List(list) => list
+                        .iter()
+                        .find(|sub| sub.name() == skip_name)
+                        .map(|sub| (sub.span(), format!("`skip({})`", skip_name), false)),
+                    _ => Some((e.item.span(), e.written.clone(), true)),
+                }
+            })
+            .collect::<Vec<_>>();
+        // a plain `#[derived(skip)]` is read by both `Gtor` and `Stor`, so only one of them warns
+        // about it
+        let skips_all = shared_skips.first().is_some_and(|(_, _, all)| *all);
+        let own_skips = entries
+            .iter()
+            .filter(|e| e.is(Namespace::Own, "skip"))
+            .map(|e| (e.item.span(), e.written.clone()));
+        let skips = shared_skips
+            .into_iter()
+            .map(|(span, written, _)| (span, written))
+            .chain(own_skips);
+        let skip = self::first_of(skips, diag);
+        // `#[phantom]` is read by all the macros but `Constdef`, so only one of them warns about
+        // it being redundant
+        let mut ignored = Diagnostics::default();
+        let phantom = self::find(
+            &entries,
+            &[
+                (Namespace::Shared, "phantom"),
+                (Namespace::Phantom, "phantom"),
+            ],
+            if parent.warns_for(&[Derive::Ctor, Derive::Gtor, Derive::Stor]) {
+                diag
+            } else {
+                &mut ignored
+            },
+        );
+        if let (Some(skip), Some(_)) = (skip, phantom) {
+            if !skips_all || parent.warns_for(&[Derive::Gtor, Derive::Stor]) {
+                diag.warning(
+                    skip,
+                    "skipping is redundant, because `#[phantom]` fields are always skipped",
+                );
+            }
+        }
+        let validate = self::find_value(&entries, (Namespace::Own, "validate"));
+        let error: Option<(Span, Type)> = self::find_value(&entries, (Namespace::Own, "error"));
+        match (&validate, &error) {
+            (Some((validate, _)), None) => diag.error(
+                *validate,
+                "`validate` needs the type of the error returned when validation fails\nhelp: add `error = YourError` to the attribute",
+            ),
+            (None, Some((error, _))) => diag.warning(
+                *error,
+                "`error` has no effect, because the field isn't validated with `validate`",
+            ),
+            _ => {}
+        }
+        if validate.is_some() {
+            // these put values in the field that never went through the validator
+            for (key, span) in [("take", take), ("swap", swap)].iter() {
+                if let Some(span) = span {
+                    diag.error(
+                        *span,
+                        format!(
+                            "`{}` can't be used on a validated field, because the value it leaves in the field isn't validated",
+                            key
+                        ),
+                    );
+                }
+            }
+        }
+        // a hook on the field replaces the struct's hooks, whichever kind they are
+        let on_change = self::find_value(&entries, (Namespace::Own, "on_change"));
+        let on_change_distinct = self::find_value(&entries, (Namespace::Own, "on_change_distinct"));
+        let (on_change, on_change_distinct) = if on_change.is_some() || on_change_distinct.is_some()
+        {
+            (on_change, on_change_distinct)
+        } else {
+            (parent.on_change.clone(), parent.on_change_distinct.clone())
+        };
+        Self {
+            phantom,
+            skip,
+            copy: self::find(
+                &entries,
+                &[(Namespace::Shared, "copy"), (Namespace::Own, "copy")],
+                diag,
+            ),
+            option: self::find(&entries, &[(Namespace::Own, "option")], diag),
+            collection: self::find(&entries, &[(Namespace::Own, "collection")], diag),
+            each: self::find_value::<LitStr>(&entries, (Namespace::Own, "each"))
+                .map(|(span, lit)| (span, lit.value())),
+            chain,
+            into,
+            replace,
+            take,
+            swap,
+            with,
+            with_const,
+            map: self::find_value(&entries, (Namespace::Own, "map")),
+            validate,
+            error,
+            generic: self::find(&entries, &[(Namespace::Own, "generic")], diag),
+            on_change,
+            on_change_distinct,
+            default: self::find_value(&entries, (Namespace::Own, "default")),
+        }
+    }
+}
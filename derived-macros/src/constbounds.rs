@@ -0,0 +1,71 @@
+use crate::constdef::type_analysis;
+use crate::diagnostics::Diagnostics;
+use crate::util;
+use ::proc_macro::TokenStream;
+use ::quote::{quote, ToTokens};
+use ::syn::{parse_macro_input, parse_quote, DeriveInput, Ident};
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = parse_macro_input!(input);
+    let struct_name = &ast.ident;
+    let mut diag = Diagnostics::default();
+    let fields = ok_else_ret!(util::get_struct_field_names(&ast));
+    let mut fallbacks = Vec::new();
+    let mut min_args = quote! {};
+    let mut max_args = quote! {};
+    for (ident, ty, attrs) in fields {
+        let min = match diag.ok(type_analysis::analyze_bound(ty, false, &mut fallbacks)) {
+            Some(min) => min,
+            None => continue,
+        };
+        // the type was already walked (and its fallbacks added) for the minimum, so this can't fail
+        let max = type_analysis::analyze_bound(ty, true, &mut Vec::new()).unwrap_or_default();
+        // only initialize the field if it exists
+        let cfg = util::get_cfg_attrs(attrs);
+        min_args = quote! {
+            #min_args
+            #cfg #ident: #min,
+        };
+        max_args = quote! {
+            #max_args
+            #cfg #ident: #max,
+        };
+    }
+    let warnings = ok_else_ret!(diag.finish());
+    // like with `Constdef`, the impls are bounded on the types that depend on the type parameters
+    let mut generics = ast.generics.clone();
+    let params: Vec<&Ident> = ast
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+    for fallback in fallbacks {
+        let tokens = fallback.to_token_stream();
+        if params
+            .iter()
+            .any(|param| util::mentions_ident(tokens.clone(), param))
+        {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #fallback: ::derived::ConstBounds });
+        }
+    }
+    let (impl_gen, ty_gen, where_clause) = generics.split_for_impl();
+    let tokens = quote! {
+        impl #impl_gen #struct_name #ty_gen #where_clause {
+            pub const MIN: Self = Self {
+                #min_args
+            };
+            pub const MAX: Self = Self {
+                #max_args
+            };
+        }
+        impl #impl_gen ::derived::ConstBounds for #struct_name #ty_gen #where_clause {
+            const MIN: Self = Self::MIN;
+            const MAX: Self = Self::MAX;
+        }
+        #warnings
+    };
+    tokens.into()
+}
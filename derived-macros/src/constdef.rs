@@ -0,0 +1,290 @@
+use crate::attrs::{Derive, FieldConfig, StructConfig, VariantConfig};
+use crate::diagnostics::Diagnostics;
+use crate::util;
+use ::proc_macro::TokenStream;
+use ::quote::{__private::Span, quote, ToTokens};
+use ::syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DataEnum, DeriveInput, Fields, Ident, Type,
+    TypePath, Variant,
+};
+// internal modules
+pub(crate) mod type_analysis;
+mod types;
+
+pub fn derive(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = parse_macro_input!(input);
+    let struct_name = ast.ident.clone();
+    let mut diag = Diagnostics::default();
+    let config = StructConfig::parse(&ast, Derive::Constdef, &mut diag);
+    let mut fallbacks = Vec::new();
+    let mut zeroable = Vec::new();
+    let value = match &ast.data {
+        _ if config.zeroed.is_some() => {
+            self::zeroed_default(&ast, &config, &mut zeroable, &mut diag)
+        }
+        Data::Struct(data) => self::fields_default(
+            quote! { Self },
+            &data.fields,
+            &config,
+            &mut fallbacks,
+            &mut diag,
+        ),
+        Data::Enum(data) => self::enum_default(&ast, data, &config, &mut fallbacks, &mut diag),
+        Data::Union(_) => {
+            diag.push(syn::Error::new_spanned(
+                &ast.ident,
+                "`Constdef` can only be used on structs and enums",
+            ));
+            quote! {}
+        }
+    };
+    let warnings = ok_else_ret!(diag.finish());
+    // the types that depend on the type parameters only implement `ConstDefault` for some of
+    // them, so the impls are bounded on these (just like `#[derive(Default)]` does)
+    let mut generics = ast.generics.clone();
+    let params: Vec<&Ident> = ast
+        .generics
+        .type_params()
+        .map(|param| &param.ident)
+        .collect();
+    let bounds = fallbacks
+        .iter()
+        .map(|ty| (ty.to_token_stream(), quote! { ::derived::ConstDefault }))
+        .chain(
+            zeroable
+                .iter()
+                .map(|ty| (ty.to_token_stream(), quote! { ::derived::ConstZeroed })),
+        );
+    for (ty, bound) in bounds {
+        if params
+            .iter()
+            .any(|param| util::mentions_ident(ty.clone(), param))
+        {
+            generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote! { #ty: #bound });
+        }
+    }
+    let (impl_gen, ty_gen, where_clause) = generics.split_for_impl();
+    // the value is only written once: the `const fn` has it if there's one, and the other items
+    // just refer to it
+    let mut inherent = quote! {};
+    let mut item = value;
+    if config.no_const_fn.is_none() {
+        let const_fn = match &config.const_fn {
+            Some((_, name)) => name.clone(),
+            None => Ident::new("default", Span::call_site()),
+        };
+        inherent = quote! {
+            pub const fn #const_fn() -> Self {
+                #item
+            }
+        };
+        item = quote! { Self::#const_fn() };
+    }
+    if config.assoc_const.is_some() {
+        inherent = quote! {
+            #inherent
+            pub const DEFAULT: Self = #item;
+        };
+        item = quote! { Self::DEFAULT };
+    }
+    let default_impl = if config.no_default_impl.is_some() {
+        quote! {}
+    } else {
+        // without any inherent items, the value is only in the `ConstDefault` impl
+        let default = if inherent.is_empty() {
+            quote! { <Self as ::derived::ConstDefault>::DEFAULT }
+        } else {
+            item.clone()
+        };
+        quote! {
+            impl #impl_gen ::core::default::Default for #struct_name #ty_gen #where_clause {
+                fn default() -> Self {
+                    #default
+                }
+            }
+        }
+    };
+    let zeroed_impl = if config.zeroed.is_some() {
+        quote! {
+            unsafe impl #impl_gen ::derived::ConstZeroed for #struct_name #ty_gen #where_clause {}
+        }
+    } else {
+        quote! {}
+    };
+    let tokens = quote! {
+        impl #impl_gen #struct_name #ty_gen #where_clause {
+            #inherent
+        }
+        #zeroed_impl
+        #default_impl
+        impl #impl_gen ::derived::ConstDefault for #struct_name #ty_gen #where_clause {
+            const DEFAULT: Self = #item;
+        }
+        #warnings
+    };
+    tokens.into()
+}
+
+/// Returns the default value for a field, adding the types that get their default from their
+/// `ConstDefault` impl to `fallbacks`. If the field's type isn't supported, the error is added to
+/// `diag`, so that all the unsupported fields are reported at once
+fn field_default<'a>(
+    ty: &'a Type,
+    attrs: &[Attribute],
+    config: &StructConfig,
+    fallbacks: &mut Vec<&'a TypePath>,
+    diag: &mut Diagnostics,
+) -> quote::__private::TokenStream {
+    let field_config = FieldConfig::parse(attrs, Derive::Constdef, config, diag);
+    if let Some((_, default)) = field_config.default {
+        // an explicit default is used as is, whatever the type is
+        return default.into_token_stream();
+    }
+    match diag.ok(self::type_analysis::analyze_type(ty, fallbacks)) {
+        Some(def) => def.into_base_token(),
+        // this is never emitted, since there's an error now
+        None => quote! { () },
+    }
+}
+
+/// Returns the value of the variant marked with `#[constdef(default)]`
+fn enum_default<'a>(
+    ast: &DeriveInput,
+    data: &'a DataEnum,
+    config: &StructConfig,
+    fallbacks: &mut Vec<&'a TypePath>,
+    diag: &mut Diagnostics,
+) -> quote::__private::TokenStream {
+    let mut default: Option<&Variant> = None;
+    for variant in data.variants.iter() {
+        let span = match VariantConfig::parse(&variant.attrs, Derive::Constdef, diag).default {
+            Some(span) => span,
+            None => continue,
+        };
+        match default {
+            Some(first) => diag.error(
+                span,
+                format!(
+                    "Only one variant can be the default, but `{}` already is",
+                    first.ident
+                ),
+            ),
+            None => default = Some(variant),
+        }
+    }
+    let variant = match default {
+        Some(variant) => variant,
+        None => {
+            diag.push(syn::Error::new_spanned(
+                &ast.ident,
+                "`Constdef` needs to know which variant is the default\nhelp: add `#[constdef(default)]` to one of the variants",
+            ));
+            return quote! {};
+        }
+    };
+    let name = &variant.ident;
+    self::fields_default(
+        quote! { Self::#name },
+        &variant.fields,
+        config,
+        fallbacks,
+        diag,
+    )
+}
+
+/// Returns the value built with `path` (like `Self` or `Self::Variant`) from the default values of
+/// `fields`
+fn fields_default<'a>(
+    path: quote::__private::TokenStream,
+    fields: &'a Fields,
+    config: &StructConfig,
+    fallbacks: &mut Vec<&'a TypePath>,
+    diag: &mut Diagnostics,
+) -> quote::__private::TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let mut args = quote! {};
+            for field in fields.named.iter() {
+                let value = self::field_default(&field.ty, &field.attrs, config, fallbacks, diag);
+                let ident = &field.ident;
+                // only initialize the field if it exists
+                let cfg = util::get_cfg_attrs(&field.attrs);
+                args = quote! {
+                    #args
+                    #cfg #ident: #value,
+                };
+            }
+            quote! { #path { #args } }
+        }
+        Fields::Unnamed(fields) => {
+            let mut args = quote! {};
+            for field in fields.unnamed.iter() {
+                let value = self::field_default(&field.ty, &field.attrs, config, fallbacks, diag);
+                args = quote! {
+                    #args
+                    #value,
+                };
+            }
+            quote! { #path(#args) }
+        }
+        Fields::Unit => path,
+    }
+}
+
+/// Returns the all-zeroes value for a `zeroed` struct, after checking that all of its fields can
+/// be zeroed. The types that have to be checked by the compiler are added to `zeroable`
+fn zeroed_default<'a>(
+    ast: &'a DeriveInput,
+    config: &StructConfig,
+    zeroable: &mut Vec<&'a Type>,
+    diag: &mut Diagnostics,
+) -> quote::__private::TokenStream {
+    let zeroed = config.zeroed.unwrap_or_else(Span::call_site);
+    let fields = match &ast.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            diag.error(zeroed, "`zeroed` can only be used on structs");
+            return quote! {};
+        }
+    };
+    let mut checks = quote! {};
+    for field in fields.iter() {
+        let (ty, attrs) = (&field.ty, &field.attrs);
+        let field_config = FieldConfig::parse(attrs, Derive::Constdef, config, diag);
+        if let Some((default, _)) = field_config.default {
+            diag.error(
+                default,
+                "`default` can't be used on the fields of a `zeroed` struct",
+            );
+        }
+        let mut field_zeroable = Vec::new();
+        if diag
+            .ok(self::type_analysis::check_zeroed(ty, &mut field_zeroable))
+            .is_none()
+        {
+            continue;
+        }
+        // only check the field if it exists
+        let cfg = util::get_cfg_attrs(attrs);
+        for ty in field_zeroable {
+            checks = quote! {
+                #checks
+                #cfg zeroable::<#ty>();
+            };
+            zeroable.push(ty);
+        }
+    }
+    quote! {
+        {
+            // the macro only knows the names of the types, so the compiler checks that all of
+            // them are really zeroable
+            const fn zeroable<T: ::derived::ConstZeroed + ?::core::marker::Sized>() {}
+            #checks
+            // SAFETY: all the fields are valid when they're all zeroes
+            unsafe { ::core::mem::zeroed() }
+        }
+    }
+}
@@ -0,0 +1,321 @@
+use super::types::{DefExpr, ATOMICS, CONSTDEF, CONST_NEW, CONTAINERS, NEWTYPES, OTHERS};
+use crate::util;
+use ::quote::{
+    __private::{Span, TokenStream},
+    quote, ToTokens,
+};
+use ::syn::{GenericArgument, Ident, PathArguments, Type, TypeArray, TypePath, TypeTuple};
+
+/// Returns an error pointing at a type that can't be evaluated at compile time
+pub(super) fn unsupported(ty: &impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(
+        ty,
+        format!(
+            "The type `{}` cannot be evaluated at compile time\nhelp: {}",
+            util::written(ty),
+            super::types::supported_types_help()
+        ),
+    )
+}
+
+/// Returns the default for any type that a field can have
+pub(crate) fn analyze_type<'a>(
+    ty: &'a Type,
+    fallbacks: &mut Vec<&'a TypePath>,
+) -> syn::Result<DefExpr> {
+    match ty {
+        Type::Path(t) => self::analyze_type_path(t, fallbacks),
+        Type::Array(arr) => self::process_array(arr, fallbacks),
+        Type::Tuple(tp) if tp.elems.is_empty() => Ok(CONSTDEF["()"].clone()),
+        Type::Tuple(tpl) => self::recursive_process_tuple(tpl, fallbacks),
+        Type::Paren(paren) => self::analyze_type(&paren.elem, fallbacks),
+        Type::Group(group) => self::analyze_type(&group.elem, fallbacks),
+        Type::Reference(reference) if reference.mutability.is_none() => match &*reference.elem {
+            Type::Slice(_) => Ok(DefExpr::Expr(quote! { &[] }.to_string())),
+            Type::Path(elem) if self::std_type_name(elem).as_deref() == Some("str") => {
+                Ok(DefExpr::Expr(quote! { "" }.to_string()))
+            }
+            _ => Err(self::unsupported(ty)),
+        },
+        Type::Ptr(ptr) if ptr.mutability.is_some() => Ok(DefExpr::Expr(
+            quote! { ::core::ptr::null_mut() }.to_string(),
+        )),
+        Type::Ptr(_) => Ok(DefExpr::Expr(quote! { ::core::ptr::null() }.to_string())),
+        other => Err(self::unsupported(other)),
+    }
+}
+
+/// Returns the default for a type path. Anything that isn't a known type from the standard
+/// library (like another `Constdef` struct, or a type parameter) gets its default from its
+/// `ConstDefault` impl, and is added to `fallbacks` so that the impl can be bounded on it
+pub(crate) fn analyze_type_path<'a>(
+    t: &'a TypePath,
+    fallbacks: &mut Vec<&'a TypePath>,
+) -> syn::Result<DefExpr> {
+    if let Some(name) = self::std_type_name(t) {
+        let name = name.as_str();
+        // the types are used as written, so that their generics are kept
+        let ty = || t.to_token_stream().to_string();
+        if let Some(def) = CONSTDEF.get(name) {
+            return Ok(def.clone());
+        }
+        if CONST_NEW.contains(&name) {
+            return Ok(DefExpr::ConstNew(ty()));
+        }
+        if let Some((_, value)) = ATOMICS.iter().find(|(atomic, _)| *atomic == name) {
+            let value = CONSTDEF[value].clone().into_base_token();
+            return Ok(DefExpr::ConstNewWith(ty(), value.to_string()));
+        }
+        if name == "AtomicPtr" {
+            let null = quote! { ::core::ptr::null_mut() };
+            return Ok(DefExpr::ConstNewWith(ty(), null.to_string()));
+        }
+        if name == "Duration" {
+            return Ok(DefExpr::Expr(quote! { <#t>::ZERO }.to_string()));
+        }
+        if let Some((_, value)) = OTHERS.iter().find(|(other, _)| *other == name) {
+            return Ok(DefExpr::Expr((*value).to_owned()));
+        }
+        if let Some(inner) = self::generic_arg(t) {
+            if CONTAINERS.contains(&name) {
+                // the container is created holding the default for its type argument
+                let inner = self::analyze_type(inner, fallbacks)?.into_base_token();
+                return Ok(DefExpr::ConstNewWith(ty(), inner.to_string()));
+            }
+            if NEWTYPES.contains(&name) {
+                let name = Ident::new(name, Span::call_site());
+                let inner = self::analyze_type(inner, fallbacks)?.into_base_token();
+                return Ok(DefExpr::Expr(
+                    quote! { ::core::num::#name(#inner) }.to_string(),
+                ));
+            }
+        }
+    }
+    fallbacks.push(t);
+    Ok(DefExpr::ConstDefault(t.to_token_stream().to_string()))
+}
+
+/// Returns the only type argument of a path like `Mutex<T>`
+fn generic_arg(t: &TypePath) -> Option<&Type> {
+    match &t.path.segments.last()?.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// Returns the name of the type, if the path could be naming a type in the standard library.
+/// That's either a bare name (like `u8` or `Vec<u8>`), or a path in `std`, `core` or `alloc` (like
+/// `core::primitive::u8` or `std::collections::BTreeMap<K, V>`)
+fn std_type_name(t: &TypePath) -> Option<String> {
+    if t.qself.is_some() {
+        return None;
+    }
+    let segments = &t.path.segments;
+    let is_std = match segments.first() {
+        _ if segments.len() == 1 => t.path.leading_colon.is_none(),
+        Some(krate) => krate.ident == "std" || krate.ident == "core" || krate.ident == "alloc",
+        None => false,
+    };
+    // only the last segment can have generics, since modules can't
+    let no_module_generics = segments
+        .iter()
+        .rev()
+        .skip(1)
+        .all(|seg| seg.arguments.is_empty());
+    if is_std && no_module_generics {
+        segments.last().map(|last| last.ident.to_string())
+    } else {
+        None
+    }
+}
+
+pub(super) fn recursive_process_tuple<'a>(
+    tuple: &'a TypeTuple,
+    fallbacks: &mut Vec<&'a TypePath>,
+) -> syn::Result<DefExpr> {
+    let inner_decl = self::tuple_elems(tuple, |elem| {
+        Ok(self::analyze_type(elem, fallbacks)?.into_base_token())
+    })?;
+    Ok(DefExpr::CustomTuple(inner_decl.to_string()))
+}
+
+pub(super) fn process_array<'a>(
+    array: &'a TypeArray,
+    fallbacks: &mut Vec<&'a TypePath>,
+) -> syn::Result<DefExpr> {
+    let ret = self::analyze_type(&array.elem, fallbacks)?.into_base_token();
+    Ok(DefExpr::CustomArray(
+        self::array_elems(array, ret).to_string(),
+    ))
+}
+
+/// Returns the elements of a tuple (without the parentheses), each of which is the value that
+/// `value` returns for its type
+fn tuple_elems<'a>(
+    tuple: &'a TypeTuple,
+    mut value: impl FnMut(&'a Type) -> syn::Result<TokenStream>,
+) -> syn::Result<TokenStream> {
+    let mut inner_decl = quote! {};
+    for elem in tuple.elems.iter() {
+        let ret = value(elem)?;
+        inner_decl = quote! {
+            #inner_decl
+            #ret,
+        };
+    }
+    Ok(inner_decl)
+}
+
+/// Returns the elements of an array (without the brackets), all of which are `ret`
+fn array_elems(array: &TypeArray, ret: TokenStream) -> TokenStream {
+    let len = &array.len;
+    // `[expr; N]` needs the element to be `Copy` (or a constant), and only the types without drop
+    // glue are known to be. For the others, the element is put in a `const` block
+    if util::has_no_drop_glue(&array.elem) {
+        quote! { #ret; #len }
+    } else {
+        quote! { const { #ret }; #len }
+    }
+}
+
+/// Returns an error pointing at a type that isn't known to be valid when it's all zeroes
+fn not_zeroable(ty: &impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(
+        ty,
+        format!(
+            "The type `{}` isn't known to be valid when it's all zeroes, so the struct can't be `zeroed`\nhelp: {}",
+            util::written(ty),
+            "the types that can be zeroed are integers, floats, `bool`, `char`, raw pointers, atomics, `PhantomData`, `Option<fn(..)>`, `Option<NonNull<T>>`, arrays, tuples and cells of them, and other `zeroed` structs"
+        ),
+    )
+}
+
+/// Checks that `ty` is valid when it's all zeroes. Only arrays and tuples can be checked here,
+/// since a type path only gives the name of a type (and a type like `AtomicBool` could have been
+/// shadowed by another one), and a raw pointer to a trait object can't be zeroed. So, the type
+/// paths and the raw pointers are added to `zeroable` to be checked against their `ConstZeroed`
+/// impls, and the names are only used to reject the types that are known to not be zeroable with
+/// a better error
+pub(super) fn check_zeroed<'a>(ty: &'a Type, zeroable: &mut Vec<&'a Type>) -> syn::Result<()> {
+    match ty {
+        Type::Ptr(_) => {
+            zeroable.push(ty);
+            Ok(())
+        }
+        Type::Array(arr) => self::check_zeroed(&arr.elem, zeroable),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .try_for_each(|elem| self::check_zeroed(elem, zeroable)),
+        Type::Paren(paren) => self::check_zeroed(&paren.elem, zeroable),
+        Type::Group(group) => self::check_zeroed(&group.elem, zeroable),
+        Type::Path(t) => {
+            self::check_zeroed_name(t)?;
+            zeroable.push(ty);
+            Ok(())
+        }
+        other => Err(self::not_zeroable(other)),
+    }
+}
+
+/// Returns an error if the path names a type from the standard library that isn't zeroable
+fn check_zeroed_name(t: &TypePath) -> syn::Result<()> {
+    let name = self::std_type_name(t);
+    match name.as_deref() {
+        Some("Option") => {
+            // `None` is represented by zeroes for the types that can't be null
+            let is_nullable = match self::generic_arg(t) {
+                Some(Type::BareFn(_)) => true,
+                Some(Type::Path(inner)) => self::std_type_name(inner).as_deref() == Some("NonNull"),
+                _ => false,
+            };
+            if is_nullable {
+                Ok(())
+            } else {
+                Err(self::not_zeroable(t))
+            }
+        }
+        Some("PhantomData") => Ok(()),
+        // these have the same layout as what they hold
+        Some("Cell" | "UnsafeCell" | "ManuallyDrop" | "Wrapping" | "Saturating") => {
+            match self::generic_arg(t) {
+                Some(inner) => self::check_zeroed(inner, &mut Vec::new()),
+                None => Ok(()),
+            }
+        }
+        Some(name) if self::is_std_type(name) => Err(self::not_zeroable(t)),
+        _ => Ok(()),
+    }
+}
+
+/// Returns true if `name` is one of the other types from the standard library that we know about,
+/// none of which can be zeroed
+fn is_std_type(name: &str) -> bool {
+    name == "NonNull"
+        || name == "Duration"
+        || name == "Box"
+        || CONST_NEW.contains(&name)
+        || CONTAINERS.contains(&name)
+        || OTHERS.iter().any(|(other, _)| *other == name)
+}
+
+/// Returns an error pointing at a type that doesn't have bounds
+fn no_bounds(ty: &impl ToTokens) -> syn::Error {
+    syn::Error::new_spanned(
+        ty,
+        format!(
+            "The type `{}` doesn't have a minimum and a maximum value\nhelp: {}",
+            util::written(ty),
+            "the supported types are integers, floats, `bool`, `char`, `()`, `Wrapping` and `Saturating`, arrays and tuples of them, and other `ConstBounds` types"
+        ),
+    )
+}
+
+/// Returns the minimum (or the maximum, if `max` is set) value of a type, for `ConstBounds`. This
+/// walks arrays and tuples just like the defaults do, and anything that isn't a known type from
+/// the standard library gets its bounds from its `ConstBounds` impl (and is added to `fallbacks`)
+pub(crate) fn analyze_bound<'a>(
+    ty: &'a Type,
+    max: bool,
+    fallbacks: &mut Vec<&'a TypePath>,
+) -> syn::Result<TokenStream> {
+    let bound = Ident::new(if max { "MAX" } else { "MIN" }, Span::call_site());
+    match ty {
+        Type::Path(t) => {
+            if let Some(name) = self::std_type_name(t) {
+                match CONSTDEF.get(name.as_str()) {
+                    Some(DefExpr::Numeric | DefExpr::Float) => return Ok(quote! { <#t>::#bound }),
+                    Some(DefExpr::Boolean) => return Ok(quote! { #max }),
+                    Some(DefExpr::Char) if max => return Ok(quote! { <#t>::MAX }),
+                    Some(DefExpr::Char) => return Ok(quote! { '\0' }),
+                    Some(DefExpr::Unit) => return Ok(quote! { () }),
+                    _ => {}
+                }
+                if let Some(inner) = self::generic_arg(t) {
+                    if NEWTYPES.contains(&name.as_str()) {
+                        let name = Ident::new(&name, Span::call_site());
+                        let inner = self::analyze_bound(inner, max, fallbacks)?;
+                        return Ok(quote! { ::core::num::#name(#inner) });
+                    }
+                }
+            }
+            fallbacks.push(t);
+            Ok(quote! { <#t as ::derived::ConstBounds>::#bound })
+        }
+        Type::Array(arr) => {
+            let elem = self::analyze_bound(&arr.elem, max, fallbacks)?;
+            let elems = self::array_elems(arr, elem);
+            Ok(quote! { [#elems] })
+        }
+        Type::Tuple(tuple) => {
+            let elems = self::tuple_elems(tuple, |elem| self::analyze_bound(elem, max, fallbacks))?;
+            Ok(quote! { (#elems) })
+        }
+        Type::Paren(paren) => self::analyze_bound(&paren.elem, max, fallbacks),
+        Type::Group(group) => self::analyze_bound(&group.elem, max, fallbacks),
+        other => Err(self::no_bounds(other)),
+    }
+}
@@ -0,0 +1,189 @@
+use ::quote::quote;
+
+#[derive(Clone)]
+pub enum DefExpr {
+    Numeric,
+    Boolean,
+    Float,
+    Char,
+    Unit,
+    CustomTuple(String),
+    CustomArray(String),
+    /// A type that gets its default from its `ConstDefault` impl
+    ConstDefault(String),
+    /// A type from the standard library with a `const fn new()`
+    ConstNew(String),
+    /// A type from the standard library with a `const fn new(value)`, along with the value
+    ConstNewWith(String, String),
+    /// Any other expression
+    Expr(String),
+}
+
+impl DefExpr {
+    /// Returns the base token's default value
+    pub(super) fn into_base_token(self) -> quote::__private::TokenStream {
+        match self {
+            DefExpr::Boolean => {
+                quote! {
+                    false
+                }
+            }
+            DefExpr::Char => {
+                quote! {
+                    '\0'
+                }
+            }
+            DefExpr::Float => {
+                quote! {
+                    0.0
+                }
+            }
+            DefExpr::Numeric => {
+                quote! {
+                    0
+                }
+            }
+            DefExpr::Unit => {
+                quote! {
+                    ()
+                }
+            }
+            DefExpr::CustomTuple(custom) => {
+                let custom: quote::__private::TokenStream = custom.parse().unwrap();
+                quote! {
+                    (#custom)
+                }
+            }
+            DefExpr::CustomArray(custom) => {
+                let custom: quote::__private::TokenStream = custom.parse().unwrap();
+                quote! {
+                    [#custom]
+                }
+            }
+            DefExpr::ConstDefault(ty) => {
+                let ty: quote::__private::TokenStream = ty.parse().unwrap();
+                quote! {
+                    <#ty as ::derived::ConstDefault>::DEFAULT
+                }
+            }
+            DefExpr::ConstNew(ty) => {
+                let ty: quote::__private::TokenStream = ty.parse().unwrap();
+                quote! {
+                    <#ty>::new()
+                }
+            }
+            DefExpr::ConstNewWith(ty, value) => {
+                let ty: quote::__private::TokenStream = ty.parse().unwrap();
+                let value: quote::__private::TokenStream = value.parse().unwrap();
+                quote! {
+                    <#ty>::new(#value)
+                }
+            }
+            DefExpr::Expr(expr) => expr.parse().unwrap(),
+        }
+    }
+}
+
+macro_rules! gen_defset {
+    ($($ty:ty => $defexpr:expr),*) => {
+        lazy_static::lazy_static! {
+            pub(super) static ref CONSTDEF: ::std::collections::HashMap<&'static str, DefExpr> = {
+                let mut hm = ::std::collections::HashMap::new();
+                $(
+                    hm.insert(stringify!($ty), $defexpr);
+                )*
+                hm
+            };
+        }
+    };
+}
+
+gen_defset! {
+    u8 => DefExpr::Numeric,
+    i8 => DefExpr::Numeric,
+    u16 => DefExpr::Numeric,
+    i16 => DefExpr::Numeric,
+    u32 => DefExpr::Numeric,
+    i32 => DefExpr::Numeric,
+    u64 => DefExpr::Numeric,
+    i64 => DefExpr::Numeric,
+    u128 => DefExpr::Numeric,
+    i128 => DefExpr::Numeric,
+    bool => DefExpr::Boolean,
+    usize => DefExpr::Numeric,
+    isize => DefExpr::Numeric,
+    char => DefExpr::Char,
+    f32  => DefExpr::Float,
+    f64  => DefExpr::Float,
+    () => DefExpr::Unit
+}
+
+/// The types from the standard library that are created empty with a `const fn new()`
+pub(super) const CONST_NEW: &[&str] = &[
+    "String",
+    "Vec",
+    "VecDeque",
+    "LinkedList",
+    "BTreeMap",
+    "BTreeSet",
+    "Condvar",
+    "Once",
+    "OnceLock",
+];
+
+/// The atomics, along with the primitive type whose default they're created with. `AtomicPtr` is
+/// handled on its own, since it's created with a null pointer
+pub(super) const ATOMICS: &[(&str, &str)] = &[
+    ("AtomicBool", "bool"),
+    ("AtomicI8", "i8"),
+    ("AtomicU8", "u8"),
+    ("AtomicI16", "i16"),
+    ("AtomicU16", "u16"),
+    ("AtomicI32", "i32"),
+    ("AtomicU32", "u32"),
+    ("AtomicI64", "i64"),
+    ("AtomicU64", "u64"),
+    ("AtomicIsize", "isize"),
+    ("AtomicUsize", "usize"),
+];
+
+/// The types that are created with a `const fn new(value)`, holding the default for their type
+/// argument
+pub(super) const CONTAINERS: &[&str] = &[
+    "Mutex",
+    "RwLock",
+    "Cell",
+    "RefCell",
+    "UnsafeCell",
+    "ManuallyDrop",
+];
+
+/// The tuple structs that hold the default for their type argument
+pub(super) const NEWTYPES: &[&str] = &["Wrapping", "Saturating"];
+
+/// The other types from the standard library, along with their defaults
+pub(super) const OTHERS: &[(&str, &str)] = &[
+    ("Option", "::core::option::Option::None"),
+    ("PhantomData", "::core::marker::PhantomData"),
+];
+
+/// Returns help text listing the supported types
+pub(super) fn supported_types_help() -> String {
+    let mut types: Vec<&str> = CONSTDEF.keys().copied().collect();
+    types.sort_unstable();
+    types.extend(CONST_NEW);
+    types.extend(ATOMICS.iter().map(|(atomic, _)| *atomic));
+    types.push("AtomicPtr");
+    types.extend(CONTAINERS);
+    types.extend(NEWTYPES);
+    types.extend(OTHERS.iter().map(|(ty, _)| *ty));
+    types.extend(&["Duration", "&str", "&[T]", "*const T", "*mut T"]);
+    format!(
+        "the supported types are {}, types that implement `ConstDefault` (like other `Constdef` structs), along with arrays and tuples of them",
+        types
+            .iter()
+            .map(|ty| format!("`{}`", ty))
+            .collect::<Vec<_>>()
+            .join(", ")
+    )
+}
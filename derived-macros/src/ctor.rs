@@ -1,14 +1,13 @@
 //! This module is responsible for the actual generation of the ctor
 //!
 
+use crate::attrs::{Derive, FieldConfig, StructConfig};
+use crate::diagnostics::Diagnostics;
 use crate::util;
-use crate::util::ATTR_PHANTOM;
 use ::proc_macro::TokenStream;
 use ::quote::quote;
 use ::syn::{parse_macro_input, DeriveInput};
 
-const ATTR_CONST_CTOR: &str = "ctor_const";
-
 pub fn derive_ctor(input: TokenStream) -> TokenStream {
     let ast: DeriveInput = parse_macro_input!(input);
     let struct_name = ast.ident.clone();
@@ -17,54 +16,54 @@ pub fn derive_ctor(input: TokenStream) -> TokenStream {
         Ok(f) => f,
         Err(e) => return e,
     };
-    err_if_subattr_on_primary_attr!(
-        "entire struct",
-        // a struct cannot be entirely phantom
-        ATTR_PHANTOM in ast.attrs,
-    );
-    let func = ok_else_ret!(util::get_func_header(&ast.attrs, ATTR_CONST_CTOR));
+    let mut diag = Diagnostics::default();
+    let config = StructConfig::parse(&ast, Derive::Ctor, &mut diag);
+    let field_configs: Vec<FieldConfig> = fields
+        .iter()
+        .map(|(_, _, attrs)| FieldConfig::parse(attrs, Derive::Ctor, &config, &mut diag))
+        .collect();
+    let warnings = ok_else_ret!(diag.finish());
+    let func = util::get_func_header(config.is_const.is_some());
     if fields.is_empty() {
         // handle fast case: empty struct
-        return {
-            quote! {
-                impl #impl_gen #struct_name #ty_gen #where_clause {
-                    #func new() -> Self {
-                        Self {}
-                    }
+        quote! {
+            impl #impl_gen #struct_name #ty_gen #where_clause {
+                #func new() -> Self {
+                    Self {}
                 }
             }
+            #warnings
         }
-        .into();
+        .into()
     } else {
         // handle extended case: struct with fields
         let mut tokens = quote! {};
         let mut self_args = quote! {};
-        for (fname, ty, attrs) in fields {
-            err_if_subattr_on_primary_attr!(
-                "field",
-                // marking const_ctor on a field is invalid
-                ATTR_CONST_CTOR in attrs,
-            );
-            let is_phantom = ok_else_ret!(util::single_instance_of_attr(attrs, util::ATTR_PHANTOM));
+        for ((fname, ty, attrs), field_config) in fields.into_iter().zip(field_configs) {
+            let is_phantom = field_config.phantom.is_some();
+            // the argument and the initializer should only exist if the field does
+            let cfg = util::get_cfg_attrs(attrs);
             if !is_phantom {
                 // not a phantomdata struct, add it
                 tokens = quote! {
                     #tokens
-                    #fname: #ty,
+                    #cfg #fname: #ty,
                 };
                 self_args = quote! {
                     #self_args
-                    #fname,
+                    #cfg #fname,
                 };
             } else {
                 self_args = quote! {
                     #self_args
-                    #fname: ::core::marker::PhantomData,
+                    #cfg #fname: ::core::marker::PhantomData,
                 };
             }
         }
         let tokens = quote! {
             impl #impl_gen #struct_name #ty_gen #where_clause {
+                // initializing a deprecated field isn't the caller's fault
+                #[allow(deprecated)]
                 #func new(
                     #tokens
                 ) -> #struct_name #ty_gen {
@@ -73,6 +72,7 @@ pub fn derive_ctor(input: TokenStream) -> TokenStream {
                     }
                 }
             }
+            #warnings
         };
         tokens.into()
     }
@@ -0,0 +1,66 @@
+//! This module collects the diagnostics found while expanding a macro, so that all of them can be
+//! reported at once instead of stopping at the first one
+//!
+//! Proc macros can't emit warnings on stable, so warnings are emitted by using a deprecated
+//! constant at the span of the offending token, which makes the compiler warn with our message
+//!
+
+use ::proc_macro::TokenStream;
+use ::quote::{__private::Span, quote, quote_spanned};
+use ::std::fmt::Display;
+use ::syn::Ident;
+
+#[derive(Default)]
+pub(crate) struct Diagnostics {
+    errors: Option<syn::Error>,
+    warnings: Vec<(Span, String)>,
+}
+
+impl Diagnostics {
+    /// Adds an error
+    pub(crate) fn push(&mut self, error: syn::Error) {
+        match &mut self.errors {
+            Some(errors) => errors.combine(error),
+            None => self.errors = Some(error),
+        }
+    }
+    /// Adds an error at `span`
+    pub(crate) fn error(&mut self, span: Span, msg: impl Display) {
+        self.push(syn::Error::new(span, msg))
+    }
+    /// Returns the value if `r` is `Ok`, otherwise adds the error and returns `None`
+    pub(crate) fn ok<T>(&mut self, r: syn::Result<T>) -> Option<T> {
+        match r {
+            Ok(v) => Some(v),
+            Err(e) => {
+                self.push(e);
+                None
+            }
+        }
+    }
+    /// Adds a warning at `span`
+    pub(crate) fn warning(&mut self, span: Span, msg: impl Display) {
+        self.warnings.push((span, msg.to_string()))
+    }
+    /// Returns all the errors (if any) as a compile error. Otherwise, returns the tokens that
+    /// emit the warnings, which should be added to the output
+    pub(crate) fn finish(self) -> Result<quote::__private::TokenStream, TokenStream> {
+        if let Some(errors) = self.errors {
+            return Err(errors.into_compile_error().into());
+        }
+        let mut ret = quote! {};
+        for (span, msg) in self.warnings {
+            let warning = Ident::new("warning", span);
+            ret = quote_spanned! {span=>
+                #ret
+                const _: () = {
+                    #[deprecated(note = #msg)]
+                    #[allow(non_upper_case_globals)]
+                    const warning: () = ();
+                    let _ = #warning;
+                };
+            };
+        }
+        Ok(ret)
+    }
+}
@@ -0,0 +1,324 @@
+//! This module is responsible for the actual generation of the gtor
+//!
+
+use crate::attrs::{Derive, FieldConfig, StructConfig};
+use crate::diagnostics::Diagnostics;
+use crate::util;
+use ::proc_macro::TokenStream;
+use ::quote::{__private::TokenStream as TokenStream2, quote, ToTokens};
+use ::syn::{parse_macro_input, DeriveInput, Ident, Type};
+use util::{Collection, TYCOPY};
+
+pub(crate) fn derive_gtor(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = parse_macro_input!(input);
+    let struct_name = ast.ident.clone();
+    // get generics
+    let (impl_gen, ty_gen, where_clause) = &ast.generics.split_for_impl();
+    // get fields
+    let fields = match util::get_struct_field_names(&ast) {
+        Ok(f) => f,
+        Err(e) => return e,
+    };
+    // get the configuration for the struct and for each field
+    let mut diag = Diagnostics::default();
+    let config = StructConfig::parse(&ast, Derive::Gtor, &mut diag);
+    let field_configs: Vec<FieldConfig> = fields
+        .iter()
+        .map(|(_, _, attrs)| FieldConfig::parse(attrs, Derive::Gtor, &config, &mut diag))
+        .collect();
+    let option_types: Vec<Option<&Type>> = fields
+        .iter()
+        .zip(&field_configs)
+        .map(|((field, ty, _), field_config)| {
+            field_config.option_type(&config, field, ty, &mut diag)
+        })
+        .collect();
+    let collection_types: Vec<Option<Collection>> = fields
+        .iter()
+        .zip(&field_configs)
+        .map(|((field, ty, _), field_config)| {
+            field_config.collection_type(&config, field, ty, &mut diag)
+        })
+        .collect();
+    for (((_, ty, _), field_config), option_type) in
+        fields.iter().zip(&field_configs).zip(&option_types)
+    {
+        // the gtors for options return the value inside the option
+        let ty = option_type.unwrap_or(ty);
+        match (
+            field_config.copy,
+            field_config.skip.or(field_config.phantom),
+        ) {
+            (Some(copy), Some(_)) => {
+                // both at once, huh?
+                diag.error(
+                    copy,
+                    "Using `#[gtor_copy]` with `#[gtor_skip]` or `#[phantom]` is invalid",
+                );
+            }
+            (Some(copy), None) if self::is_copy_type(ty) => {
+                diag.warning(
+                    copy,
+                    format!(
+                        "`#[gtor_copy]` is redundant, because `{}` is always returned by value",
+                        util::written(ty)
+                    ),
+                );
+            }
+            _ => {}
+        }
+    }
+    let warnings = ok_else_ret!(diag.finish());
+    let needs_get = config.get.is_some();
+    let needs_get_mut = config.get_mut.is_some();
+    // get function header
+    let func = util::get_func_header(config.is_const.is_some());
+    if !fields.is_empty() {
+        let mut q = quote!();
+        for ((((field, ty, attrs), field_config), option_type), collection_type) in fields
+            .into_iter()
+            .zip(field_configs)
+            .zip(option_types)
+            .zip(collection_types)
+        {
+            let is_explicitly_copy = field_config.copy.is_some();
+            if let (Some(inner), None, None) =
+                (option_type, field_config.skip, field_config.phantom)
+            {
+                // not skipped and an option, so add the gtors for options
+                let field_name_str = util::get_field_name(field);
+                let carry = util::get_accessor_attrs(attrs);
+                let is_prim = self::is_copy_type(inner);
+                let gtors = self::gen_option_gtors(
+                    (&struct_name, field, &field_name_str, inner),
+                    (needs_get, needs_get_mut),
+                    is_prim || is_explicitly_copy,
+                    (&func, &carry),
+                );
+                q = quote! {
+                    #q
+                    #gtors
+                };
+                continue;
+            }
+            if field_config.skip.is_none() && field_config.phantom.is_none() {
+                // not skipped and not phantom, so add gtor
+                let is_prim = self::is_copy_type(ty);
+                let field_name_str = util::get_field_name(field);
+                // carry `cfg` and `deprecated` over from the field
+                let carry = util::get_accessor_attrs(attrs);
+
+                if needs_get {
+                    let mut fname = "get_".to_owned();
+                    fname.push_str(&field_name_str);
+                    let doc_comment = format!(
+                        "Returns the value for the `{field}` field in struct [`{struct_name}`]",
+                        struct_name = struct_name,
+                        field = field_name_str
+                    );
+                    let fname = Ident::new(&fname, field.span());
+
+                    if is_prim || is_explicitly_copy {
+                        // a copy-able type
+                        q = quote! {
+                            #q
+                            #[doc = #doc_comment]
+                            #carry
+                            #func #fname(&self) -> #ty {
+                                self.#field
+                            }
+                        };
+                    } else {
+                        q = quote! {
+                            #q
+                            #[doc = #doc_comment]
+                            #carry
+                            #func #fname(&self) -> &#ty {
+                                &self.#field
+                            }
+                        };
+                    }
+                }
+                if needs_get_mut {
+                    let fname = format!("get_{field}_mut", field = field_name_str);
+                    let doc_comment = format!(
+                        "Returns a mutable reference to the `{field}` field in struct [`{struct_name}`]",
+                        struct_name = struct_name,
+                        field = field_name_str
+                    );
+                    let fname = Ident::new(&fname, field.span());
+
+                    q = quote! {
+                        #q
+                        #[doc = #doc_comment]
+                        #carry
+                        pub fn #fname(&mut self) -> &mut #ty {
+                            &mut self.#field
+                        }
+                    };
+                }
+                if let Some(collection) = collection_type {
+                    let gtors = self::gen_collection_gtors(
+                        (&struct_name, field, &field_name_str, collection),
+                        &carry,
+                    );
+                    q = quote! {
+                        #q
+                        #gtors
+                    };
+                }
+            }
+        }
+        q = quote! {
+            impl #impl_gen #struct_name #ty_gen #where_clause {
+                #q
+            }
+            #warnings
+        };
+        q.into()
+    } else {
+        warnings.into()
+    }
+}
+
+/// Generates the gtors for an `Option<T>` field (with `inner` being the `T`), which give access to
+/// the value inside the option instead of the option itself
+fn gen_option_gtors(
+    (struct_name, field, field_name_str, inner): (&Ident, &Ident, &str, &Type),
+    (needs_get, needs_get_mut): (bool, bool),
+    is_copy: bool,
+    (func, carry): (&TokenStream2, &TokenStream2),
+) -> TokenStream2 {
+    let has_fname = Ident::new(&format!("has_{}", field_name_str), field.span());
+    let has_doc = format!(
+        "Returns true if the `{field}` field in struct [`{struct_name}`] is set",
+        struct_name = struct_name,
+        field = field_name_str
+    );
+    let mut q = quote! {
+        #[doc = #has_doc]
+        #carry
+        #func #has_fname(&self) -> bool {
+            self.#field.is_some()
+        }
+    };
+    if needs_get {
+        let fname = Ident::new(&format!("get_{}", field_name_str), field.span());
+        let doc_comment = format!(
+            "Returns the value for the `{field}` field in struct [`{struct_name}`], if it is set",
+            struct_name = struct_name,
+            field = field_name_str
+        );
+        let or_fname = Ident::new(&format!("get_{}_or", field_name_str), field.span());
+        let or_doc = format!(
+            "Returns the value for the `{field}` field in struct [`{struct_name}`], or `default` if it isn't set",
+            struct_name = struct_name,
+            field = field_name_str
+        );
+        if is_copy {
+            // a copy-able type
+            q = quote! {
+                #q
+                #[doc = #doc_comment]
+                #carry
+                #func #fname(&self) -> ::core::option::Option<#inner> {
+                    self.#field
+                }
+                #[doc = #or_doc]
+                #carry
+                #func #or_fname(&self, default: #inner) -> #inner {
+                    match self.#field {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => default,
+                    }
+                }
+            };
+        } else {
+            // the struct may already have a lifetime named `'a`, so this one has to be unusual
+            q = quote! {
+                #q
+                #[doc = #doc_comment]
+                #carry
+                #func #fname(&self) -> ::core::option::Option<&#inner> {
+                    self.#field.as_ref()
+                }
+                #[doc = #or_doc]
+                #carry
+                #func #or_fname<'__derived>(
+                    &'__derived self,
+                    default: &'__derived #inner,
+                ) -> &'__derived #inner {
+                    match &self.#field {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => default,
+                    }
+                }
+            };
+        }
+    }
+    if needs_get_mut {
+        let fname = Ident::new(&format!("get_{}_mut", field_name_str), field.span());
+        let doc_comment = format!(
+            "Returns a mutable reference to the value for the `{field}` field in struct [`{struct_name}`], if it is set",
+            struct_name = struct_name,
+            field = field_name_str
+        );
+        q = quote! {
+            #q
+            #[doc = #doc_comment]
+            #carry
+            pub fn #fname(&mut self) -> ::core::option::Option<&mut #inner> {
+                self.#field.as_mut()
+            }
+        };
+    }
+    q
+}
+
+/// Generates the gtors for the elements of a collection field
+fn gen_collection_gtors(
+    (struct_name, field, field_name_str, collection): (&Ident, &Ident, &str, Collection),
+    carry: &TokenStream2,
+) -> TokenStream2 {
+    let len_fname = Ident::new(&format!("{}_len", field_name_str), field.span());
+    let len_doc = format!(
+        "Returns the number of elements in the `{field}` field in struct [`{struct_name}`]",
+        struct_name = struct_name,
+        field = field_name_str
+    );
+    let iter_fname = Ident::new(&format!("iter_{}", field_name_str), field.span());
+    let iter_doc = format!(
+        "Returns an iterator over the elements in the `{field}` field in struct [`{struct_name}`]",
+        struct_name = struct_name,
+        field = field_name_str
+    );
+    let item = match collection {
+        Collection::Seq(elem, _) | Collection::Set(elem, _) => quote! { &#elem },
+        Collection::Map(key, value, _) => quote! { (&#key, &#value) },
+    };
+    quote! {
+        #[doc = #len_doc]
+        #carry
+        pub fn #len_fname(&self) -> usize {
+            self.#field.len()
+        }
+        #[doc = #iter_doc]
+        #carry
+        pub fn #iter_fname(&self) -> impl ::core::iter::Iterator<Item = #item> + '_ {
+            self.#field.iter()
+        }
+    }
+}
+
+/// Returns true if the getter for `ty` can return a copy without being told to
+fn is_copy_type(ty: &Type) -> bool {
+    match ty {
+        Type::Path(t) => {
+            let type_str = t.clone().into_token_stream().to_string();
+            TYCOPY.contains(type_str.as_str())
+        }
+        // all these are copy type (fnptrs, ptrs, refs); no point in returning another ref
+        Type::BareFn(_) | Type::Never(_) | Type::Ptr(_) | Type::Reference(_) => true,
+        _ => false,
+    }
+}
@@ -0,0 +1,924 @@
+//! The derive macros for the [`derived`](https://docs.rs/derived) crate
+//!
+//! This crate isn't meant to be used directly: depend on `derived` instead, which re-exports all
+//! the macros along with the traits that the generated code uses.
+//!
+
+use ::proc_macro::TokenStream;
+#[macro_use]
+mod macros;
+mod attrs;
+mod constbounds;
+mod constdef;
+mod ctor;
+mod diagnostics;
+mod gtor;
+mod stor;
+mod util;
+
+#[proc_macro_derive(Ctor, attributes(derived, ctor, ctor_const, phantom))]
+/// # Ctor: Get a constructor derived
+///
+/// The [`Ctor`] macro will take the fields in the order they are declared and generate a
+/// constructor, that is a `YourStruct::new()` function.
+///
+///
+/// ## Example
+/// ```
+/// use derived::Ctor;
+///
+/// #[derive(Ctor)]
+/// struct MyStruct {
+///     int: u32,
+///     unsigned_int: i32,
+/// }
+///
+/// let ms = MyStruct::new(1, -1);
+/// assert_eq!(ms.int, 1);
+/// assert_eq!(ms.unsigned_int, -1);
+/// ```
+///
+/// # Attributes
+///
+/// The following attributes are available:
+/// - `#[ctor_const]` or `#[ctor(const)]`: Will make your ctors constant
+/// - `#[phantom]` or `#[derived(phantom)]`: Will skip the specified
+///   [`PhantomData`](core::marker::PhantomData) field(s) in the constructor, automatically adding
+///   `PhantomData` in the requisite positions
+///
+/// ## Constant constructors
+///
+/// To make your constructors `const`, simply add the `#[ctor_const]` attribute to the top
+/// of your struct.
+///
+/// ### Example
+///
+/// ```
+/// use derived::Ctor;
+///
+/// #[derive(Ctor)]
+/// #[ctor_const]
+/// pub struct MyConst {
+///     a: u8,
+///     b: u8,
+/// }
+/// // you can now use it in constant contexts
+/// const MC: MyConst = MyConst::new(1, 2);
+/// ```
+///
+pub fn derive_ctor(input: TokenStream) -> TokenStream {
+    ctor::derive_ctor(input)
+}
+
+#[proc_macro_derive(
+    Gtor,
+    attributes(derived, gtor, gtor_const, gtor_copy, gtor_skip, phantom)
+)]
+/// # Gtor: Get the getters derived
+///
+/// Gtor takes the fields in order and generates getters for each field. For example,
+/// if you have fields named `userid` and `name`, then the getters generated will be
+/// `get_userid` and `get_name`, returning references to the appropriate types. In other
+/// words, `get_*` named methods will be derived per your fields.
+///
+/// ## Example
+/// ```
+/// use derived::Gtor;
+/// #[derive(Gtor)]
+/// struct MyStruct {
+///     name: String,
+///     userid: u64,
+/// }
+///
+/// let ms = MyStruct { name: "Sayan".to_owned(), userid: 16 };
+/// assert_eq!(ms.get_name(), "Sayan");
+/// ```
+/// # Attributes
+///
+/// The following attributes are available:
+/// - `#[gtor_const]` or `#[gtor(const)]`: Will make your gtors constant
+/// - `#[gtor_skip]` or `#[gtor(skip)]`: Will skip generation of getters for specific fields
+/// - `#[gtor_copy]` or `#[gtor(copy)]`: Makes the getter return a copy of the value, assuming that
+///   the type is [`Copy`]
+/// - `#[gtor(option)]`: For `Option<T>` fields, generates getters for the value inside the option
+///   (see [this example](#option-fields)). This can be used on the entire struct (applying to all
+///   the fields whose type is written as `Option<T>`), or on specific fields
+/// - `#[gtor(collection)]`: For collection fields, also generates `*_len()` and `iter_*()` (see
+///   [this example](Stor#collection-fields)). This can be used on the entire struct (applying to
+///   all the fields that are collections), or on specific fields
+/// - `#[phantom]` or `#[derived(phantom)]`: Marks the field as a
+///   [`PhantomData`](core::marker::PhantomData) field, hence skipping getters, setters and ctors for
+///   the field
+/// - `#[gtor(...)]`: See [this example](#the-gtor-attribute)
+///
+/// ## The `gtor` attribute
+///
+/// Simply add the gtor attribute like this: `#[gtor(get, get_mut)]` on the top of your struct to
+/// get mutable and immutable reference methods to the fields in your struct. Add `no_get` (like
+/// `#[gtor(get_mut, no_get)]`) to only generate the mutable ones.
+///
+/// ### Example
+///
+/// ```
+/// use derived::{Ctor, Gtor};
+/// #[derive(Ctor, Gtor)]
+/// #[gtor(get, get_mut)]
+/// pub struct Mutable {
+///     x_axis: u8,
+///     y_axis: u8,
+/// }
+///
+/// let mut m = Mutable::new(0, 0);
+/// // move x by 1 unit
+/// *m.get_x_axis_mut() = 1;
+/// // move y by 2 units
+/// *m.get_y_axis_mut() = 2;
+/// assert_eq!(m.get_x_axis(), 1);
+/// assert_eq!(m.get_y_axis(), 2);
+/// ```
+///
+/// ## Option fields
+///
+/// With `#[gtor(option)]`, an `Option<T>` field gets `has_*() -> bool`, `get_*() -> Option<&T>`
+/// and `get_*_or(default: &T) -> &T` (along with `get_*_mut() -> Option<&mut T>`, if mutable
+/// getters are generated). Like other getters, these return a copy for primitive types and with
+/// `#[gtor_copy]`. Combined with `#[stor(option)]`:
+///
+/// ```
+/// use derived::{Ctor, Gtor, Stor};
+///
+/// #[derive(Ctor, Gtor, Stor)]
+/// #[gtor(option)]
+/// #[stor(option)]
+/// pub struct Profile {
+///     nickname: Option<String>,
+///     age: Option<u8>,
+/// }
+///
+/// let mut profile = Profile::new(None, None);
+/// assert!(!profile.has_nickname());
+/// // `set_*` wraps the value in `Some`
+/// profile.set_nickname("sayan".to_owned());
+/// profile.set_age(21);
+/// assert_eq!(profile.get_nickname().map(String::as_str), Some("sayan"));
+/// assert_eq!(profile.get_age_or(0), 21);
+/// // `take_*` and `clear_*` leave the field unset
+/// assert_eq!(profile.take_nickname().as_deref(), Some("sayan"));
+/// profile.clear_age();
+/// assert_eq!(profile.get_age(), None);
+/// ```
+///
+/// # Important notes
+///
+/// ## References
+/// If any of the fields within the struct are primitive types that do not require large copies,
+/// then the value is returned directly instead of a reference to it:
+/// ```text
+/// u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, str, bool, usize, isize, char, f32, f64
+/// ```
+///
+/// ## Doc-comments
+///
+/// The [`Gtor`] macro will automatically add a doc comment of the form:
+/// ```text
+/// Returns the value for the `<struct_field>` field in struct [`<struct_name>`]
+/// ```
+///
+pub fn derive_gtor(input: TokenStream) -> TokenStream {
+    gtor::derive_gtor(input)
+}
+
+#[proc_macro_derive(Stor, attributes(derived, stor, stor_const, stor_skip, phantom))]
+/// # Stor: Get the setters derived
+///
+/// Stor takes the fields in order and generates setters for each field. For example,
+/// if you have fields named `userid` and `name`, then the setters generated will be
+/// `set_userid` and `set_name`, accepting values for the appropriate types. In other
+/// words, `set_*` named methods will be derived per your fields.
+///
+/// ## Example
+/// ```
+/// use derived::Stor;
+/// #[derive(Stor)]
+/// struct MyStruct {
+///     name: String,
+///     userid: u64,
+/// }
+///
+/// let mut ms = MyStruct { name: "Sayan".to_owned(), userid: 1 };
+/// assert_eq!(ms.name, "Sayan");
+/// assert_eq!(ms.userid, 1);
+/// ms.set_userid(0);
+/// assert_eq!(ms.userid, 0);
+/// ```
+///
+/// # Attributes
+///
+/// The following attributes are available:
+/// - `#[phantom]` or `#[derived(phantom)]`: Skips the stor for the specified field(s), assuming
+///   they are [`PhantomData`](core::marker::PhantomData) fields. This has the same effect as
+///   `#[stor_skip]` but it makes it easier to use with the other macros, avoiding the need to write
+///   skips for phantom fields specifically
+/// - `#[stor_const]` or `#[stor(const)]`: Will make your setters constant (see
+///   [this example](#const-stors))
+/// - `#[stor_skip]` or `#[stor(skip)]`: Skips the stor for the specified field(s)
+/// - `#[stor(chain)]`: Makes the setters return `&mut Self` so that calls can be chained. This
+///   can be used on the entire struct, or on specific fields
+/// - `#[stor(into)]`: Makes the setters (and the `with_*` methods) accept anything that implements
+///   [`Into`] for the field's type, like a `&str` for a `String` field. This can be used on the
+///   entire struct, or on specific fields
+/// - `#[stor(option)]`: For `Option<T>` fields, makes the setter take a `T` and wrap it in `Some`,
+///   and also generates `clear_*` and `take_*` methods (see [this example](Gtor#option-fields)).
+///   This can be used on the entire struct (applying to all the fields whose type is written as
+///   `Option<T>`), or on specific fields
+/// - `#[stor(collection)]`: For collection fields, also generates stors for their elements (see
+///   [this example](#collection-fields)). This can be used on the entire struct (applying to all
+///   the fields that are collections), or on specific fields
+/// - `#[stor(each = "name")]`: Names the stors that add or remove a single element of a collection
+///   after `name` (like `add_name` and `remove_name`)
+/// - `#[stor(map = path)]`: Passes the value through the given function (of the form
+///   `fn(T) -> T`) before assigning it, to normalize it. For example, this can trim strings or
+///   clamp numbers
+/// - `#[stor(validate = path, error = Type)]`: Validates the value with the given function (of
+///   the form `fn(&T) -> Result<(), E>`) before assigning it. The setter is then named `try_set_*`
+///   and returns `Result<(), Type>`, and the value is only assigned if it passes validation. The
+///   validator's error is converted with [`From`], so `E` can be `Type` itself, or any type that
+///   `Type` can be created from (see [this example](#validating-and-normalizing-setters))
+/// - `#[stor(on_change = method)]`: Makes the stors call `self.method("field", &old, &new)` after
+///   assigning a new value. Use `on_change_distinct` instead to only call it when the new value is
+///   different from the old one (see [this example](#change-notifications)). This can be used on
+///   the entire struct, or on specific fields (where it replaces the struct's hook)
+/// - `#[stor(generic)]`: For a field whose type is one of the struct's type parameters, makes the
+///   setter take the struct by value and return it with the parameter changed, along with a
+///   `map_*` method (see [this example](#changing-the-type-of-a-field))
+/// - `#[stor(replace)]`, `#[stor(take)]` and `#[stor(swap)]`: Also generate methods that give the
+///   previous value back (see [this example](#getting-the-previous-value-back)). This can be used
+///   on the entire struct, or on specific fields
+/// - `#[stor(with)]`: Also generates `with_*` methods, that take the struct by value and return
+///   it with the field updated. Use `#[stor(with(const))]` to make them `const` (see
+///   [this example](#the-with-methods)). This can be used on the entire struct, or on specific
+///   fields
+///
+/// ## Chaining setters
+///
+/// ```
+/// use derived::{Ctor, Stor};
+///
+/// #[derive(Ctor, Stor)]
+/// #[stor(chain)]
+/// pub struct Config {
+///     host: String,
+///     port: u16,
+///     tls: bool,
+/// }
+///
+/// let mut cfg = Config::new("localhost".to_owned(), 0, false);
+/// cfg.set_port(80).set_host("example.com".to_owned()).set_tls(true);
+/// assert_eq!(cfg.port, 80);
+/// assert_eq!(cfg.host, "example.com");
+/// assert!(cfg.tls);
+/// ```
+///
+/// ## Setters that convert
+///
+/// ```
+/// use derived::{Ctor, Stor};
+/// use std::borrow::Cow;
+///
+/// #[derive(Ctor, Stor)]
+/// #[stor(into, chain)]
+/// pub struct User {
+///     name: String,
+///     bio: String,
+///     age: u64,
+/// }
+///
+/// let mut user = User::new("sayan".to_owned(), String::new(), 0);
+/// user.set_name("ohsayan").set_bio(Cow::Borrowed("hi")).set_age(21u8);
+/// assert_eq!(user.name, "ohsayan");
+/// assert_eq!(user.bio, "hi");
+/// assert_eq!(user.age, 21);
+/// ```
+///
+/// Since [`Into::into`] can't be called in a `const fn`, `into` can't be used along with
+/// `#[stor(with(const))]` for the same field.
+///
+/// ## Validating and normalizing setters
+///
+/// ```
+/// use derived::{Ctor, Stor};
+///
+/// fn trim(name: String) -> String {
+///     name.trim().to_owned()
+/// }
+///
+/// fn validate_name(name: &String) -> Result<(), String> {
+///     if name.is_empty() {
+///         Err("the name can't be empty".to_owned())
+///     } else {
+///         Ok(())
+///     }
+/// }
+///
+/// #[derive(Ctor, Stor)]
+/// pub struct User {
+///     #[stor(map = trim, validate = validate_name, error = String)]
+///     name: String,
+///     #[stor(map = |age: u8| age.min(150))]
+///     age: u8,
+/// }
+///
+/// let mut user = User::new("sayan".to_owned(), 21);
+/// assert!(user.try_set_name("  ".to_owned()).is_err());
+/// assert_eq!(user.name, "sayan");
+/// user.try_set_name(" ohsayan ".to_owned()).unwrap();
+/// assert_eq!(user.name, "ohsayan");
+/// user.set_age(200);
+/// assert_eq!(user.age, 150);
+/// ```
+///
+/// The value is converted (with `into`) first, then normalized (with `map`) and then validated
+/// (with `validate`). When validated, chained setters return `Result<&mut Self, Type>` and the
+/// `with_*` methods are named `try_with_*` and return `Result<Self, Type>`.
+///
+/// ## Change notifications
+///
+/// ```
+/// use derived::{Ctor, Stor};
+/// use std::cell::Cell;
+///
+/// #[derive(Ctor, Stor)]
+/// #[stor(on_change_distinct = invalidate)]
+/// pub struct View {
+///     title: String,
+///     width: u32,
+///     #[stor_skip]
+///     redraws: Cell<u32>,
+/// }
+///
+/// impl View {
+///     // the hook is called with the name of the field, and the old and new values
+///     fn invalidate<T: ?Sized>(&self, _field: &str, _old: &T, _new: &T) {
+///         self.redraws.set(self.redraws.get() + 1);
+///     }
+/// }
+///
+/// let mut view = View::new("derived".to_owned(), 80, Cell::new(0));
+/// view.set_width(100);
+/// // same value, so no redraw
+/// view.set_width(100);
+/// view.set_title("docs".to_owned());
+/// assert_eq!(view.redraws.get(), 2);
+/// ```
+///
+/// Since the hook is given a reference to the new value in the field, it takes `&self` (so it
+/// needs interior mutability to change anything), and it has to accept the types of all the fields
+/// it's used for (usually by being generic). The hooks are called by `set_*`, `with_*`,
+/// `replace_*`, `take_*`, `swap_*` and `clear_*`, but not by the stors for the elements of a
+/// collection or the ones generated with `generic`. `on_change_distinct` needs the type to
+/// implement [`PartialEq`]. A hook set on a field replaces the struct's hook for that field, even
+/// if it's the other kind of hook.
+///
+/// ## Changing the type of a field
+///
+/// ```
+/// use derived::{Ctor, Stor};
+///
+/// #[derive(Ctor, Stor)]
+/// pub struct Tagged<T> {
+///     #[stor(generic)]
+///     tag: T,
+///     id: u64,
+/// }
+///
+/// let tagged: Tagged<&str> = Tagged::new("42", 1);
+/// // fn set_tag<U>(self, tag: U) -> Tagged<U>
+/// let tagged: Tagged<u8> = tagged.set_tag(42);
+/// // fn map_tag<U>(self, f: impl FnOnce(T) -> U) -> Tagged<U>
+/// let tagged: Tagged<String> = tagged.map_tag(|tag| tag.to_string());
+/// assert_eq!(tagged.tag, "42");
+/// assert_eq!(tagged.id, 1);
+/// ```
+///
+/// The bounds on the type parameter (including the ones in the `where` clause) are carried over
+/// to the new one. Since the other fields are moved over as they are, none of them can use the
+/// same type parameter (not even `#[phantom]` fields). These setters take the value as is and are
+/// never `const`, so `chain`, `into`, `map` and `validate` can't be used on the field, and
+/// `#[stor_const]` doesn't apply to it.
+///
+/// ## Collection fields
+///
+/// With `#[stor(collection)]`, `Vec`, `VecDeque`, `HashMap`, `BTreeMap`, `HashSet` and `BTreeSet`
+/// fields get these stors (shown here for a field named `x`):
+/// - `push_x(value)` for `Vec` and `VecDeque`, or `insert_x(value)` and `remove_x(&value)` for
+///   sets, or `insert_x(key, value)` and `remove_x(&key)` for maps
+/// - `extend_x(iter)` and `clear_x()`
+///
+/// With `#[gtor(collection)]`, they also get `x_len()` and `iter_x()`. The collections are
+/// detected by the name of the type, so they can be written with their full path too.
+///
+/// ```
+/// use derived::{Ctor, Gtor, Stor};
+/// use std::collections::HashMap;
+///
+/// #[derive(Ctor, Gtor, Stor)]
+/// #[gtor(collection)]
+/// #[stor(collection)]
+/// pub struct Inventory {
+///     #[stor(each = "item")]
+///     items: Vec<String>,
+///     prices: HashMap<String, u32>,
+/// }
+///
+/// let mut inventory = Inventory::new(vec![], HashMap::new());
+/// // `add_item` instead of `push_items`, thanks to `each`
+/// inventory.add_item("apple".to_owned());
+/// inventory.extend_items(vec!["pear".to_owned()]);
+/// inventory.insert_prices("apple".to_owned(), 10);
+/// assert_eq!(inventory.items_len(), 2);
+/// assert_eq!(inventory.iter_items().next().unwrap(), "apple");
+/// assert_eq!(inventory.remove_prices(&"apple".to_owned()), Some(10));
+/// inventory.clear_items();
+/// assert_eq!(inventory.items_len(), 0);
+/// ```
+///
+/// Since the elements don't go through the validator, these can't be used on validated fields.
+///
+/// ## Getting the previous value back
+///
+/// ```
+/// use derived::{Ctor, Stor};
+///
+/// #[derive(Ctor, Stor)]
+/// #[stor(replace, take, swap)]
+/// pub struct Session {
+///     token: String,
+/// }
+///
+/// let mut session = Session::new("a".to_owned());
+/// // `replace_*` sets the value and returns the old one
+/// assert_eq!(session.replace_token("b".to_owned()), "a");
+/// // `take_*` leaves the default value in its place, so the type must implement `Default`
+/// assert_eq!(session.take_token(), "b");
+/// assert_eq!(session.token, "");
+/// // `swap_*` swaps the value with another one
+/// let mut other = "c".to_owned();
+/// session.swap_token(&mut other);
+/// assert_eq!(session.token, "c");
+/// assert_eq!(other, "");
+/// ```
+///
+/// `replace_*` goes through `into`, `map` and `validate` just like the setter (and is named
+/// `try_replace_*` when validated). Since `take_*` and `swap_*` leave a value in the field that
+/// doesn't go through the validator, they can't be used on validated fields.
+///
+/// ## The `with_*` methods
+///
+/// These are useful for building up immutable values, and when made `const`, they can be used
+/// along with [`Constdef`] to build values at compile time:
+///
+/// ```
+/// use derived::{Constdef, Stor};
+///
+/// #[derive(Clone, Copy, Constdef)]
+/// pub struct Color {
+///     rgb: (u8, u8, u8),
+/// }
+///
+/// #[derive(Constdef, Stor)]
+/// #[stor(with(const))]
+/// pub struct Config {
+///     port: u16,
+///     timeout: Option<u32>,
+///     color: Color,
+/// }
+///
+/// const CFG: Config = Config::default()
+///     .with_port(8080)
+///     .with_timeout(Some(30))
+///     .with_color(Color { rgb: (255, 0, 0) });
+/// assert_eq!(CFG.port, 8080);
+/// assert_eq!(CFG.timeout, Some(30));
+/// assert_eq!(CFG.color.rgb, (255, 0, 0));
+/// ```
+///
+/// ## `const` stors
+///
+/// With `#[stor_const]`, the setters can be called in `const` contexts, like when building tables
+/// at compile time:
+///
+/// ```
+/// use derived::{Ctor, Stor};
+///
+/// #[derive(Clone, Copy, Ctor, Stor)]
+/// #[ctor_const]
+/// #[stor_const]
+/// pub struct Entry {
+///     code: u8,
+///     name: &'static str,
+/// }
+///
+/// const TABLE: [Entry; 3] = {
+///     let mut table = [Entry::new(0, "unknown"); 3];
+///     table[1].set_code(1);
+///     table[1].set_name("one");
+///     table[2].set_code(2);
+///     table[2].set_name("two");
+///     table
+/// };
+/// assert_eq!(TABLE[2].code, 2);
+/// assert_eq!(TABLE[1].name, "one");
+/// ```
+///
+/// Assigning a new value drops the old one, which can't happen in a `const fn` if the type has
+/// drop glue. So, `const` stors can only be generated for fields whose types don't have drop glue
+/// (like primitives, references, options of them or any `Copy` type), and the other fields (like
+/// `String`s) have to be skipped with `#[stor_skip]`. `#[stor_const]` only applies to the setters,
+/// while `#[stor(with(const))]` makes the `with_*` methods `const`.
+///
+/// ## Doc-comments
+///
+/// The [`Stor`] macro will automatically add a doc comment of the form:
+/// ```text
+/// Sets the value for the `<struct_field>` field in struct [`<struct_name>`]
+/// ```
+///
+pub fn derive_stor(input: TokenStream) -> TokenStream {
+    stor::derive_stor(input)
+}
+
+#[proc_macro_derive(Constdef, attributes(derived, constdef))]
+/// # `Constdef`: Constant, compile-time default implementations
+///
+/// Overcome the limits of the default trait to get constant, compile-time default implementations.
+///
+/// ## Why, and how?
+/// Implementations of the [`Default`](core::default::Default) trait cannot unfortunately be called
+/// in `const` contexts due to the [current limitations with traits per RFC 911](https://rust-lang.github.io/rfcs/0911-const-fn.html#detailed-design).
+/// To overcome this limitation, this crate _hacks around_ the problem by evaluating types at
+/// compile time and substituting requisite values. A `const fn default()` is implemented for the struct,
+/// along with the [`Default`] trait, enabling you to use it other contexts that need you to use
+/// default values, along with `const` contexts. The `derived::ConstDefault` trait is implemented
+/// too, so that the struct can be used as a field in other `Constdef` structs.
+///
+/// ## Example
+///
+/// ```
+/// use derived::Constdef;
+///
+/// #[derive(Constdef)]
+/// pub struct Constable {
+///     x: u8,
+///     boolean: bool,
+///     integer: i32,
+///     small_float: core::primitive::f32,
+///     big_float: std::primitive::f64,
+///     // arrays? check!
+///     num_array: [u8; 10],
+///     bool_array: [bool; 20],
+///     float_array: [f32; 30],
+///     // tuples? check!
+///     tuple: (u8, u16),
+///     // nested tuples? check!
+///     nested_tuple: ((u8, u8), u16),
+///     // nested arrays? check!
+///     nested_array: [[f32; 10]; 10],
+///     // tuples nested in arrays? check!
+///     nested_tuple_in_array: [(u8, u8); 10],
+///     // arrays nested in tuples? check!
+///     nested_array_in_tuple: (u8, [u8; 10]),
+/// }
+///
+///
+/// const CONSTABLE: Constable = Constable::default();
+/// assert_eq!(CONSTABLE.x, 0);
+/// assert!(!CONSTABLE.boolean);
+/// assert_eq!(CONSTABLE.integer, 0);
+/// assert_eq!(CONSTABLE.num_array, [0; 10]);
+/// assert_eq!(CONSTABLE.bool_array, [false; 20]);
+/// assert_eq!(CONSTABLE.float_array, [0.0; 30]);
+/// assert_eq!(CONSTABLE.small_float, 0.0);
+/// assert_eq!(CONSTABLE.big_float, 0.0);
+/// assert_eq!(CONSTABLE.tuple, (0, 0));
+/// assert_eq!(CONSTABLE.nested_tuple, ((0, 0), 0));
+/// assert_eq!(CONSTABLE.nested_array, [[0.0; 10]; 10]);
+/// assert_eq!(CONSTABLE.nested_tuple_in_array, [(0, 0); 10]);
+/// assert_eq!(CONSTABLE.nested_array_in_tuple, (0, [0; 10]));
+/// ```
+///
+/// ## Custom defaults
+///
+/// Use `#[constdef(default = value)]` on a field to pick its default value. The value can be any
+/// expression that can be evaluated in a `const fn` (and it's used as is), so this also works for
+/// fields whose types aren't supported otherwise:
+///
+/// ```
+/// use derived::Constdef;
+/// use std::num::NonZeroU32;
+///
+/// #[derive(Constdef)]
+/// pub struct Server {
+///     #[constdef(default = 8080)]
+///     port: u16,
+///     #[constdef(default = Self::MAX_RETRIES)]
+///     retries: u8,
+///     #[constdef(default = "localhost")]
+///     host: &'static str,
+///     #[constdef(default = match NonZeroU32::new(4) { Some(n) => n, None => panic!() })]
+///     workers: NonZeroU32,
+///     verbose: bool,
+/// }
+///
+/// impl Server {
+///     const MAX_RETRIES: u8 = 3;
+/// }
+///
+/// const SERVER: Server = Server::default();
+/// assert_eq!(SERVER.port, 8080);
+/// assert_eq!(SERVER.retries, 3);
+/// assert_eq!(SERVER.host, "localhost");
+/// assert_eq!(SERVER.workers.get(), 4);
+/// assert!(!SERVER.verbose);
+/// ```
+///
+/// ## Configuring the output
+///
+/// By default, a `pub const fn default()` is generated along with the [`Default`] and
+/// `derived::ConstDefault` impls. Since that shadows [`Default::default`] (and conflicts with
+/// `#[derive(Default)]`), this can be changed with these keys on the struct:
+///
+/// - `#[constdef(const_fn = name)]`: Names the `const fn` differently, like `const_default`
+/// - `#[constdef(no_const_fn)]`: Doesn't generate the `const fn` at all
+/// - `#[constdef(assoc_const)]`: Generates a `pub const DEFAULT: Self` associated constant
+/// - `#[constdef(no_default_impl)]`: Doesn't implement [`Default`], so that it can be implemented
+///   (or derived) separately
+///
+/// The impls then use the item that is generated.
+///
+/// ```
+/// use derived::Constdef;
+///
+/// #[derive(Constdef, Default)]
+/// #[constdef(const_fn = const_default, assoc_const, no_default_impl)]
+/// pub struct Limits {
+///     max_depth: u8,
+///     names: Vec<String>,
+/// }
+///
+/// const LIMITS: Limits = Limits::const_default();
+/// assert_eq!(LIMITS.max_depth, 0);
+/// assert!(Limits::DEFAULT.names.is_empty());
+/// // this is the derived `Default`
+/// assert_eq!(Limits::default().max_depth, 0);
+/// ```
+///
+/// ## Enums
+///
+/// `Constdef` can be used on an enum too, if one of its variants is marked with
+/// `#[constdef(default)]` (just like [`Default`] with `#[default]`). If that variant has fields,
+/// they get their default values just like the fields of a struct do:
+///
+/// ```
+/// use derived::Constdef;
+///
+/// #[derive(Constdef, Debug, PartialEq)]
+/// pub enum Level {
+///     Quiet,
+///     #[constdef(default)]
+///     Normal,
+///     Verbose,
+/// }
+///
+/// #[derive(Constdef, Debug, PartialEq)]
+/// pub enum Output {
+///     Stdout,
+///     #[constdef(default)]
+///     File {
+///         #[constdef(default = "out.log")]
+///         path: &'static str,
+///         level: Level,
+///         append: bool,
+///     },
+/// }
+///
+/// const OUTPUT: Output = Output::default();
+/// assert_eq!(
+///     OUTPUT,
+///     Output::File { path: "out.log", level: Level::Normal, append: false }
+/// );
+/// ```
+///
+/// ## Zeroed structs
+///
+/// For plain data structs (like the ones that are passed over FFI), `#[constdef(zeroed)]` makes
+/// the default value the one where all the bytes are zero, which is created with
+/// [`core::mem::zeroed`] (in an `unsafe` block) instead of field by field. All the fields must be
+/// valid when they're all zeroes, which the compiler checks with the `derived::ConstZeroed` trait:
+/// it's implemented for integers, floats, `bool`, `char`, raw pointers, atomics, `PhantomData`,
+/// `Option<fn(..)>`, `Option<NonNull<T>>`, along with arrays, tuples and cells of them, and for
+/// the `zeroed` structs, so that they can be nested:
+///
+/// ```
+/// use derived::Constdef;
+/// use std::ptr::NonNull;
+///
+/// #[derive(Constdef)]
+/// #[constdef(zeroed)]
+/// #[repr(C)]
+/// pub struct Header {
+///     magic: u32,
+///     flags: [u8; 4],
+/// }
+///
+/// #[derive(Constdef)]
+/// #[constdef(zeroed)]
+/// #[repr(C)]
+/// pub struct Packet {
+///     header: Header,
+///     callback: Option<extern "C" fn(*mut u8)>,
+///     data: Option<NonNull<u8>>,
+///     raw: *const u8,
+/// }
+///
+/// const PACKET: Packet = Packet::default();
+/// assert_eq!(PACKET.header.magic, 0);
+/// assert!(PACKET.callback.is_none());
+/// assert!(PACKET.data.is_none());
+/// assert!(PACKET.raw.is_null());
+/// ```
+///
+/// `zeroed` can't be used on enums, or along with `#[constdef(default = value)]` on fields.
+///
+/// ## Supported types
+///
+/// - The following primitive types are supported:
+///     ```text
+///     u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, str, bool, usize, isize, char, f32, f64, ()
+///     ```
+/// - The collections from the standard library that can be created empty in a `const fn` are
+///   supported (with any element types, and named with or without their full path):
+///     ```text
+///     String, Vec, VecDeque, LinkedList, BTreeMap, BTreeSet
+///     ```
+///     So `Constdef` can be used for `static`s that are filled in later:
+///     ```
+///     use derived::Constdef;
+///     use std::collections::BTreeMap;
+///     use std::sync::Mutex;
+///
+///     #[derive(Constdef)]
+///     pub struct Registry {
+///         names: Vec<String>,
+///         ids: BTreeMap<String, u32>,
+///     }
+///
+///     static REGISTRY: Mutex<Registry> = Mutex::new(Registry::default());
+///     REGISTRY.lock().unwrap().names.push("derived".to_owned());
+///     assert_eq!(REGISTRY.lock().unwrap().names, ["derived"]);
+///     ```
+/// - The atomics (`AtomicBool`, `AtomicU64`, `AtomicPtr` and the others) are supported, along with
+///   `Mutex`, `RwLock`, `Condvar`, `Once` and `OnceLock`. `Mutex` and `RwLock` hold the default for
+///   their own type, so they can be nested too:
+///     ```
+///     use derived::Constdef;
+///     use std::sync::atomic::{AtomicU64, Ordering};
+///     use std::sync::{Mutex, OnceLock};
+///
+///     #[derive(Constdef)]
+///     pub struct Stats {
+///         requests: AtomicU64,
+///         latencies: Mutex<(u64, Vec<u64>)>,
+///         started_by: OnceLock<String>,
+///     }
+///
+///     static STATS: Stats = Stats::default();
+///     STATS.requests.fetch_add(1, Ordering::Relaxed);
+///     STATS.latencies.lock().unwrap().1.push(42);
+///     assert_eq!(STATS.requests.load(Ordering::Relaxed), 1);
+///     assert_eq!(STATS.latencies.lock().unwrap().0, 0);
+///     ```
+/// - `Option<T>` is `None`, `PhantomData<T>` is `PhantomData`, `Duration` is `Duration::ZERO`,
+///   `&str` is `""`, `&[T]` is `&[]`, and raw pointers are null. `Cell<T>`, `RefCell<T>`,
+///   `UnsafeCell<T>`, `ManuallyDrop<T>`, `Wrapping<T>` and `Saturating<T>` hold the default for
+///   their own type:
+///     ```
+///     use derived::Constdef;
+///     use std::cell::Cell;
+///     use std::marker::PhantomData;
+///     use std::num::Wrapping;
+///     use std::time::Duration;
+///
+///     #[derive(Constdef)]
+///     pub struct Entry<T> {
+///         label: &'static str,
+///         aliases: &'static [&'static str],
+///         parent: Option<Box<Entry<T>>>,
+///         timeout: Duration,
+///         hits: Cell<Wrapping<u8>>,
+///         next: *const Entry<T>,
+///         _kind: PhantomData<T>,
+///     }
+///
+///     const ENTRY: Entry<()> = Entry::default();
+///     assert_eq!(ENTRY.label, "");
+///     assert!(ENTRY.aliases.is_empty() && ENTRY.parent.is_none() && ENTRY.next.is_null());
+///     assert_eq!(ENTRY.timeout, Duration::ZERO);
+///     assert_eq!(ENTRY.hits.get(), Wrapping(0));
+///     ```
+/// - All arrays of the above types are supported, including the ones that aren't `Copy` (like
+///   `[String; 4]` or `[Mutex<u32>; 16]`) and the ones with a const generic length:
+///     ```
+///     use derived::Constdef;
+///     use std::sync::Mutex;
+///
+///     #[derive(Constdef)]
+///     pub struct Shards<const N: usize> {
+///         names: [String; N],
+///         counters: [Mutex<u64>; N],
+///     }
+///
+///     static SHARDS: Shards<16> = Shards::default();
+///     *SHARDS.counters[3].lock().unwrap() += 1;
+///     assert_eq!(*SHARDS.counters[3].lock().unwrap(), 1);
+///     assert!(SHARDS.names.iter().all(String::is_empty));
+///     ```
+/// - All tuples and nested tuples of the above types are supported
+/// - All nested arrays of the above types are supported
+/// - Nesting tuples inside arrays is supported
+/// - Nesting arrays inside tuples is supported
+/// - Any other type that implements `derived::ConstDefault` (like another `Constdef` struct) is
+///   supported, and so are type parameters that are bound by it. The `Default` and `ConstDefault`
+///   impls are bounded on the types of such fields that use type parameters:
+///     ```
+///     use derived::{ConstDefault, Constdef};
+///
+///     #[derive(Constdef)]
+///     pub struct Retries {
+///         max: u8,
+///     }
+///
+///     #[derive(Constdef)]
+///     pub struct Client<T> {
+///         retries: Retries,
+///         state: T,
+///     }
+///
+///     // `Client<T>` only implements `ConstDefault` if `T` does
+///     const CLIENT: Client<[u64; 2]> = Client::DEFAULT;
+///     assert_eq!(CLIENT.retries.max, 0);
+///     assert_eq!(CLIENT.state, [0; 2]);
+///     ```
+pub fn derive_constdef(input: TokenStream) -> TokenStream {
+    constdef::derive(input)
+}
+
+#[proc_macro_derive(ConstBounds)]
+/// # `ConstBounds`: Constant minimum and maximum values
+///
+/// Generates a `pub const MIN: Self` and a `pub const MAX: Self` for a struct, with every field at
+/// its own minimum or maximum: the numeric fields are at their `MIN` and `MAX`, `bool` fields are
+/// `false` and `true` and `char` fields are `'\0'` and [`char::MAX`]. Arrays and tuples are
+/// handled recursively, and so are `Wrapping` and `Saturating`. The `derived::ConstBounds` trait
+/// is implemented too, so that the struct can be used as a field in other `ConstBounds` structs
+/// (or be held by a type parameter bound by `ConstBounds`).
+///
+/// This is handy for range checks, or for sentinel values that sort before or after all the
+/// others.
+///
+/// ## Example
+///
+/// ```
+/// use derived::ConstBounds;
+///
+/// #[derive(ConstBounds, Clone, Copy, Debug, PartialEq, PartialOrd)]
+/// pub struct Version {
+///     major: u16,
+///     minor: u16,
+/// }
+///
+/// #[derive(ConstBounds, Debug, PartialEq, PartialOrd)]
+/// pub struct SortKey {
+///     version: Version,
+///     stable: bool,
+///     // arrays and tuples? check!
+///     checksum: [u8; 4],
+///     range: (i32, f64),
+/// }
+///
+/// assert_eq!(Version::MIN, Version { major: 0, minor: 0 });
+/// assert_eq!(SortKey::MAX.version.major, u16::MAX);
+/// assert!(SortKey::MAX.stable);
+/// assert_eq!(SortKey::MAX.checksum, [u8::MAX; 4]);
+/// assert_eq!(SortKey::MIN.range, (i32::MIN, f64::MIN));
+///
+/// let key = SortKey {
+///     version: Version { major: 1, minor: 2 },
+///     stable: true,
+///     checksum: [0xab; 4],
+///     range: (-1, 1.0),
+/// };
+/// assert!(SortKey::MIN < key && key < SortKey::MAX);
+/// ```
+pub fn derive_constbounds(input: TokenStream) -> TokenStream {
+    constbounds::derive(input)
+}
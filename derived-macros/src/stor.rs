@@ -0,0 +1,726 @@
+//! This module is responsible for the actual generation of the stor
+//!
+
+use crate::attrs::{Derive, FieldConfig, StructConfig};
+use crate::diagnostics::Diagnostics;
+use crate::util::{self, Collection, StructFields};
+use ::proc_macro::TokenStream;
+use ::quote::{
+    __private::{Span, TokenStream as TokenStream2},
+    quote, ToTokens,
+};
+use ::syn::{parse_macro_input, spanned::Spanned, DeriveInput, GenericParam, Ident, Type};
+
+pub(crate) fn derive_stor(input: TokenStream) -> TokenStream {
+    let ast: DeriveInput = parse_macro_input!(input);
+    let struct_name = ast.ident.clone();
+    let (impl_gen, ty_gen, where_clause) = &ast.generics.split_for_impl();
+    let fields = ok_else_ret!(util::get_struct_field_names(&ast));
+    let mut diag = Diagnostics::default();
+    let config = StructConfig::parse(&ast, Derive::Stor, &mut diag);
+    let field_configs: Vec<FieldConfig> = fields
+        .iter()
+        .map(|(_, _, attrs)| FieldConfig::parse(attrs, Derive::Stor, &config, &mut diag))
+        .collect();
+    let mut q = quote!();
+    for (&(field, ty, attrs), field_config) in fields.iter().zip(field_configs) {
+        if field_config.skip.is_some() || field_config.phantom.is_some() {
+            // skipped or phantom, so no stors for this one
+            continue;
+        }
+        let field_name_str = util::get_field_name(field);
+        // carry `cfg` and `deprecated` over from the field
+        let carry = util::get_accessor_attrs(attrs);
+        // the stors for options take the value inside the option and wrap it in `Some`
+        let option_type = field_config.option_type(&config, field, ty, &mut diag);
+        let (value_ty, value) = match option_type {
+            Some(inner) => (inner, quote! { ::core::option::Option::Some(#field) }),
+            None => (ty, quote! { #field }),
+        };
+        // `into` stors take anything that can be converted into the field's type
+        let param_ty = if field_config.into.is_some() {
+            quote! { impl ::core::convert::Into<#value_ty> }
+        } else {
+            quote! { #value_ty }
+        };
+        // the value is converted, normalized and then validated, in that order
+        let mut prepare = quote! {};
+        if field_config.into.is_some() {
+            prepare = quote! { let #field: #value_ty = ::core::convert::Into::into(#field); };
+        }
+        if let Some((_, map)) = &field_config.map {
+            prepare = quote! { #prepare let #field: #value_ty = (#map)(#field); };
+        }
+        if let Some((_, validate)) = &field_config.validate {
+            prepare = quote! { #prepare (#validate)(&#field)?; };
+        }
+        let error_ty = field_config
+            .validate
+            .as_ref()
+            .and(field_config.error.as_ref())
+            .map(|(_, error)| error);
+        let validated = if field_config.validate.is_some() {
+            ", if it passes validation"
+        } else {
+            ""
+        };
+        // validated setters are fallible, so they're named `try_*`
+        let try_prefix = if field_config.validate.is_some() {
+            "try_"
+        } else {
+            ""
+        };
+        // with hooks, the old value has to be kept around so that they can be given both values
+        let has_hooks =
+            field_config.on_change.is_some() || field_config.on_change_distinct.is_some();
+        let hooks = self::gen_hooks(&field_config, field, quote! { &old });
+        let assign = if has_hooks {
+            quote! {
+                let old = ::core::mem::replace(&mut self.#field, #value);
+                #hooks
+            }
+        } else {
+            quote! { self.#field = #value; }
+        };
+        // setters that change the type of the struct can't be `const`
+        let set_const = config.is_const.filter(|_| field_config.generic.is_none());
+        let set_is_const = self::check_const(set_const, &field_config, field, ty, &mut diag);
+        if field_config.generic.is_some() {
+            // the setter changes the type of the struct, so it's generated differently
+            if let Some(generic) = self::gen_generic(
+                &ast,
+                &fields,
+                (field, ty),
+                &field_config,
+                &config,
+                &carry,
+                &mut diag,
+            ) {
+                q = quote! { #q #generic };
+            }
+        } else {
+            let doc_comment = format!(
+                "Sets the value for the `{field}` field in struct [`{struct_name}`]{validated}",
+                struct_name = struct_name,
+                field = field_name_str,
+                validated = validated
+            );
+            let fname = Ident::new(
+                &format!("{}set_{}", try_prefix, field_name_str),
+                field.span(),
+            );
+            // chained setters return `&mut Self` so that calls can be chained
+            let (ret_ty, ret) = match (field_config.chain.is_some(), error_ty) {
+                (true, Some(error_ty)) => (
+                    quote! { -> ::core::result::Result<&mut Self, #error_ty> },
+                    quote! { ::core::result::Result::Ok(self) },
+                ),
+                (false, Some(error_ty)) => (
+                    quote! { -> ::core::result::Result<(), #error_ty> },
+                    quote! { ::core::result::Result::Ok(()) },
+                ),
+                (true, None) => (quote! { -> &mut Self }, quote! { self }),
+                (false, None) => (quote! {}, quote! {}),
+            };
+            let func = util::get_func_header(set_is_const);
+            q = quote! {
+                #q
+                #[doc = #doc_comment]
+                #carry
+                #func #fname(&mut self, #field: #param_ty) #ret_ty {
+                    #prepare
+                    #assign
+                    #ret
+                }
+            };
+        }
+        if field_config.replace.is_some() {
+            let fname = Ident::new(
+                &format!("{}replace_{}", try_prefix, field_name_str),
+                field.span(),
+            );
+            let doc_comment = format!(
+                "Sets the value for the `{field}` field in struct [`{struct_name}`]{validated}, returning the old value",
+                struct_name = struct_name,
+                field = field_name_str,
+                validated = validated
+            );
+            let (ret_ty, ret) = match error_ty {
+                Some(error_ty) => (
+                    quote! { ::core::result::Result<#ty, #error_ty> },
+                    quote! { ::core::result::Result::Ok(old) },
+                ),
+                None => (quote! { #ty }, quote! { old }),
+            };
+            q = quote! {
+                #q
+                #[doc = #doc_comment]
+                #carry
+                pub fn #fname(&mut self, #field: #param_ty) -> #ret_ty {
+                    #prepare
+                    let old = ::core::mem::replace(&mut self.#field, #value);
+                    #hooks
+                    #ret
+                }
+            };
+        }
+        if let Some(inner) = option_type {
+            if let Some(take) = field_config.take.filter(|_| config.take.is_none()) {
+                diag.warning(
+                    take,
+                    "`take` is redundant, because options always get a `take_*` method",
+                );
+            }
+            let fname = Ident::new(&format!("clear_{}", field_name_str), field.span());
+            let doc_comment = format!(
+                "Clears the value for the `{field}` field in struct [`{struct_name}`]",
+                struct_name = struct_name,
+                field = field_name_str
+            );
+            let (ret_ty, ret) = if field_config.chain.is_some() {
+                (quote! { -> &mut Self }, quote! { self })
+            } else {
+                (quote! {}, quote! {})
+            };
+            let func = util::get_func_header(set_is_const);
+            let clear = if has_hooks {
+                quote! {
+                    let old = self.#field.take();
+                    #hooks
+                }
+            } else {
+                quote! { self.#field = ::core::option::Option::None; }
+            };
+            q = quote! {
+                #q
+                #[doc = #doc_comment]
+                #carry
+                #func #fname(&mut self) #ret_ty {
+                    #clear
+                    #ret
+                }
+            };
+            let fname = Ident::new(&format!("take_{}", field_name_str), field.span());
+            let doc_comment = format!(
+                "Takes the value of the `{field}` field in struct [`{struct_name}`], leaving it unset",
+                struct_name = struct_name,
+                field = field_name_str
+            );
+            q = quote! {
+                #q
+                #[doc = #doc_comment]
+                #carry
+                pub fn #fname(&mut self) -> ::core::option::Option<#inner> {
+                    let old = self.#field.take();
+                    #hooks
+                    old
+                }
+            };
+        } else if field_config.take.is_some() {
+            let fname = Ident::new(&format!("take_{}", field_name_str), field.span());
+            let doc_comment = format!(
+                "Takes the value of the `{field}` field in struct [`{struct_name}`], leaving the default value in its place",
+                struct_name = struct_name,
+                field = field_name_str
+            );
+            q = quote! {
+                #q
+                #[doc = #doc_comment]
+                #carry
+                pub fn #fname(&mut self) -> #ty {
+                    let old = ::core::mem::take(&mut self.#field);
+                    #hooks
+                    old
+                }
+            };
+        }
+        if let Some(collection) = field_config.collection_type(&config, field, ty, &mut diag) {
+            if field_config.validate.is_some() {
+                // the elements would be added without going through the validator
+                diag.error(
+                    field_config.collection.or(config.collection).unwrap(),
+                    format!(
+                        "Can't generate the stors for the elements of the `{}` field, because it is validated",
+                        field_name_str
+                    ),
+                );
+            }
+            let each = field_config.each.as_ref().map(|(_, each)| each.as_str());
+            let stors = self::gen_collection_stors(
+                (&struct_name, field, &field_name_str, collection),
+                each,
+                field_config.chain.is_some(),
+                &carry,
+            );
+            q = quote! { #q #stors };
+        }
+        if field_config.swap.is_some() {
+            let fname = Ident::new(&format!("swap_{}", field_name_str), field.span());
+            let doc_comment = format!(
+                "Swaps the value of the `{field}` field in struct [`{struct_name}`] with `other`",
+                struct_name = struct_name,
+                field = field_name_str
+            );
+            let hooks = self::gen_hooks(&field_config, field, quote! { &*other });
+            q = quote! {
+                #q
+                #[doc = #doc_comment]
+                #carry
+                pub fn #fname(&mut self, other: &mut #ty) {
+                    ::core::mem::swap(&mut self.#field, other);
+                    #hooks
+                }
+            };
+        }
+        if field_config.with.is_some() {
+            let is_const = match (set_const, field_config.with_const) {
+                // already checked for the setter, so don't report the same errors twice
+                (Some(_), Some(_)) => set_is_const,
+                (_, with_const) => {
+                    self::check_const(with_const, &field_config, field, ty, &mut diag)
+                }
+            };
+            let func = util::get_func_header(is_const);
+            let fname = Ident::new(
+                &format!("{}with_{}", try_prefix, field_name_str),
+                field.span(),
+            );
+            let doc_comment = format!(
+                "Returns the struct [`{struct_name}`] with the `{field}` field set to the given value{validated}",
+                struct_name = struct_name,
+                field = field_name_str,
+                validated = validated
+            );
+            let (ret_ty, ret) = match error_ty {
+                Some(error_ty) => (
+                    quote! { ::core::result::Result<Self, #error_ty> },
+                    quote! { ::core::result::Result::Ok(self) },
+                ),
+                None => (quote! { Self }, quote! { self }),
+            };
+            q = quote! {
+                #q
+                #[doc = #doc_comment]
+                #carry
+                #func #fname(mut self, #field: #param_ty) -> #ret_ty {
+                    #prepare
+                    #assign
+                    #ret
+                }
+            };
+        }
+    }
+    let warnings = ok_else_ret!(diag.finish());
+    quote! {
+        impl #impl_gen #struct_name #ty_gen #where_clause {
+            #q
+        }
+        #warnings
+    }
+    .into()
+}
+
+/// Returns true if a `const` stor was asked for (`const_span`) and can be generated for the
+/// field. Since the old value is dropped when a new one is assigned, this is only possible if
+/// the type has no drop glue, and if the value isn't converted or validated at runtime
+fn check_const(
+    const_span: Option<Span>,
+    field_config: &FieldConfig,
+    field: &Ident,
+    ty: &Type,
+    diag: &mut Diagnostics,
+) -> bool {
+    if const_span.is_none() {
+        return false;
+    }
+    // trait methods and `?` can't be used in a `const fn`
+    let runtime_only = [
+        (
+            field_config.into,
+            "into",
+            "`into` needs to call `Into::into`",
+        ),
+        (
+            field_config.validate.as_ref().map(|(span, _)| *span),
+            "validate",
+            "`validate` needs to use the `?` operator",
+        ),
+        (
+            field_config.on_change.as_ref().map(|(span, _)| *span),
+            "on_change",
+            "`on_change` needs to call a method that isn't known to be `const`",
+        ),
+        (
+            field_config
+                .on_change_distinct
+                .as_ref()
+                .map(|(span, _)| *span),
+            "on_change_distinct",
+            "`on_change_distinct` needs to compare the values with `PartialEq`",
+        ),
+    ];
+    for (span, key, reason) in runtime_only.iter() {
+        if let Some(span) = span {
+            diag.error(
+                *span,
+                format!(
+                    "Can't generate a `const` stor for the `{}` field, because {}\nhelp: don't use `{}` on this field, or don't make its stors `const`",
+                    util::get_field_name(field),
+                    reason,
+                    key
+                ),
+            );
+        }
+    }
+    // the compiler rejects all the types with drop glue, but the common ones get a better error
+    if util::has_drop_glue(ty) {
+        diag.push(syn::Error::new_spanned(
+            ty,
+            format!(
+                "Can't generate a `const` stor for the `{}` field, because `{}` has drop glue\nhelp: skip it with `#[stor_skip]`, or don't make its stors `const`",
+                util::get_field_name(field),
+                util::written(ty)
+            ),
+        ));
+    }
+    true
+}
+
+/// Generates `set_*` and `map_*` for a field whose type is one of the struct's type parameters,
+/// which take the struct by value and return it with the parameter replaced. The other fields
+/// can't use the same parameter, since their types would then have to change too
+fn gen_generic(
+    ast: &DeriveInput,
+    fields: &StructFields<'_>,
+    (field, ty): (&Ident, &Type),
+    field_config: &FieldConfig,
+    config: &StructConfig,
+    carry: &TokenStream2,
+    diag: &mut Diagnostics,
+) -> Option<TokenStream2> {
+    let struct_name = &ast.ident;
+    let field_name_str = util::get_field_name(field);
+    let param = match ty {
+        Type::Path(path) if path.qself.is_none() => path.path.get_ident(),
+        _ => None,
+    }
+    .filter(|ident| ast.generics.type_params().any(|tp| tp.ident == **ident));
+    let param = match param {
+        Some(param) => param,
+        None => {
+            let params: Vec<String> = ast
+                .generics
+                .type_params()
+                .map(|tp| format!("`{}`", tp.ident))
+                .collect();
+            let help = if params.is_empty() {
+                "the struct doesn't have any type parameters".to_owned()
+            } else {
+                format!("the struct's type parameters are: {}", params.join(", "))
+            };
+            diag.push(syn::Error::new_spanned(
+                ty,
+                format!(
+                    "`generic` can only be used on fields whose type is one of the struct's type parameters, but the type of the `{}` field is `{}`\nhelp: {}",
+                    field_name_str,
+                    util::written(ty),
+                    help
+                ),
+            ));
+            return None;
+        }
+    };
+    // the options that change how the value is taken don't apply here. If they were set for the
+    // entire struct, this field just doesn't use them
+    let mut valid = true;
+    let own_hook = |hook: &Option<(Span, Ident)>, parent: &Option<(Span, Ident)>| {
+        hook.as_ref()
+            .filter(|_| parent.is_none())
+            .map(|(span, _)| *span)
+    };
+    let unsupported = [
+        (
+            field_config.chain.filter(|_| config.chain.is_none()),
+            "chain",
+        ),
+        (
+            own_hook(&field_config.on_change, &config.on_change),
+            "on_change",
+        ),
+        (
+            own_hook(&field_config.on_change_distinct, &config.on_change_distinct),
+            "on_change_distinct",
+        ),
+        (field_config.into.filter(|_| config.into.is_none()), "into"),
+        (field_config.map.as_ref().map(|(span, _)| *span), "map"),
+        (
+            field_config.validate.as_ref().map(|(span, _)| *span),
+            "validate",
+        ),
+    ];
+    for (span, key) in unsupported.iter() {
+        if let Some(span) = span {
+            diag.error(
+                *span,
+                format!(
+                    "`{}` can't be used on the `{}` field, because its setter changes the type of the struct",
+                    key, field_name_str
+                ),
+            );
+            valid = false;
+        }
+    }
+    for (other, other_ty, _) in fields.iter().filter(|(other, _, _)| *other != field) {
+        if util::mentions_ident(other_ty.to_token_stream(), param) {
+            diag.error(
+                ty.span(),
+                format!(
+                    "Can't change the type of the `{}` field, because the type parameter `{}` is also used by the `{}` field",
+                    field_name_str,
+                    param,
+                    util::get_field_name(other)
+                ),
+            );
+            valid = false;
+        }
+    }
+    if !valid {
+        return None;
+    }
+    // pick a name for the new parameter that doesn't clash with the struct's parameters
+    let taken: Vec<String> = ast
+        .generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Type(tp) => tp.ident.to_string(),
+            GenericParam::Const(cp) => cp.ident.to_string(),
+            GenericParam::Lifetime(lt) => lt.lifetime.ident.to_string(),
+        })
+        .collect();
+    let new_param = (0..)
+        .map(|i| match i {
+            0 => "U".to_owned(),
+            i => format!("U{}", i),
+        })
+        .find(|name| !taken.contains(name))
+        .map(|name| Ident::new(&name, Span::call_site()))
+        .unwrap();
+    // the new parameter has to satisfy all the bounds that the old one did
+    let mut bounds = quote! {};
+    for tp in ast.generics.type_params() {
+        let tp_bounds = &tp.bounds;
+        if tp.ident == *param && !tp_bounds.is_empty() {
+            let tp_bounds = util::replace_ident(tp_bounds.to_token_stream(), param, &new_param);
+            bounds = quote! { #bounds #new_param: #tp_bounds, };
+        } else if util::mentions_ident(tp_bounds.to_token_stream(), param) {
+            let ident = &tp.ident;
+            let tp_bounds = util::replace_ident(tp_bounds.to_token_stream(), param, &new_param);
+            bounds = quote! { #bounds #ident: #tp_bounds, };
+        }
+    }
+    if let Some(where_clause) = &ast.generics.where_clause {
+        for predicate in &where_clause.predicates {
+            let predicate = predicate.to_token_stream();
+            if util::mentions_ident(predicate.clone(), param) {
+                let predicate = util::replace_ident(predicate, param, &new_param);
+                bounds = quote! { #bounds #predicate, };
+            }
+        }
+    }
+    let (_, ty_gen, _) = ast.generics.split_for_impl();
+    let new_ty_gen = util::replace_ident(ty_gen.to_token_stream(), param, &new_param);
+    // move the other fields over as they are
+    let mut others = quote! {};
+    for (other, _, attrs) in fields.iter().filter(|(other, _, _)| *other != field) {
+        let cfg = util::get_cfg_attrs(attrs);
+        others = quote! { #others #cfg #other: self.#other, };
+    }
+    let set_fname = Ident::new(&format!("set_{}", field_name_str), field.span());
+    let set_doc = format!(
+        "Sets the value for the `{field}` field in struct [`{struct_name}`], changing its type",
+        struct_name = struct_name,
+        field = field_name_str
+    );
+    let map_fname = Ident::new(&format!("map_{}", field_name_str), field.span());
+    let map_doc = format!(
+        "Maps the value of the `{field}` field in struct [`{struct_name}`] with `f`, changing its type",
+        struct_name = struct_name,
+        field = field_name_str
+    );
+    Some(quote! {
+        #[doc = #set_doc]
+        #carry
+        pub fn #set_fname<#new_param>(self, #field: #new_param) -> #struct_name #new_ty_gen
+        where
+            #bounds
+        {
+            #struct_name { #field, #others }
+        }
+        #[doc = #map_doc]
+        #carry
+        pub fn #map_fname<#new_param>(
+            self,
+            f: impl ::core::ops::FnOnce(#param) -> #new_param,
+        ) -> #struct_name #new_ty_gen
+        where
+            #bounds
+        {
+            #struct_name { #field: f(self.#field), #others }
+        }
+    })
+}
+
+/// Returns the calls to the hooks set for the field with `on_change` and `on_change_distinct`, which
+/// are given the name of the field, the old value (`old`, as a reference) and the new one
+fn gen_hooks(field_config: &FieldConfig, field: &Ident, old: TokenStream2) -> TokenStream2 {
+    let field_name_str = util::get_field_name(field);
+    let mut q = quote! {};
+    if let Some((_, hook)) = &field_config.on_change {
+        q = quote! {
+            self.#hook(#field_name_str, #old, &self.#field);
+        };
+    }
+    if let Some((_, hook)) = &field_config.on_change_distinct {
+        q = quote! {
+            #q
+            if #old != &self.#field {
+                self.#hook(#field_name_str, #old, &self.#field);
+            }
+        };
+    }
+    q
+}
+
+/// Generates the stors for the elements of a collection field. If a name for a single element
+/// was given with `each`, it is used for the methods that add or remove an element
+fn gen_collection_stors(
+    (struct_name, field, field_name_str, collection): (&Ident, &Ident, &str, Collection<'_>),
+    each: Option<&str>,
+    chain: bool,
+    carry: &TokenStream2,
+) -> TokenStream2 {
+    let name = |prefix: &str, default: &str| {
+        let name = match each {
+            Some(each) => format!("{}_{}", prefix, each),
+            None => format!("{}_{}", default, field_name_str),
+        };
+        Ident::new(&name, field.span())
+    };
+    let doc = |what: &str| {
+        format!(
+            "{what} the `{field}` field in struct [`{struct_name}`]",
+            what = what,
+            struct_name = struct_name,
+            field = field_name_str
+        )
+    };
+    let (ret_ty, ret) = if chain {
+        (quote! { -> &mut Self }, quote! { self })
+    } else {
+        (quote! {}, quote! {})
+    };
+    let extend_fname = Ident::new(&format!("extend_{}", field_name_str), field.span());
+    let extend_doc = doc("Adds the elements from `iter` to");
+    let clear_fname = Ident::new(&format!("clear_{}", field_name_str), field.span());
+    let clear_doc = doc("Removes all the elements from");
+    let (item, bounds, q) = match collection {
+        Collection::Seq(elem, push) => {
+            let fname = name("add", "push");
+            let push = Ident::new(push, Span::call_site());
+            let push_doc = doc("Adds `value` to the end of");
+            let q = quote! {
+                #[doc = #push_doc]
+                #carry
+                pub fn #fname(&mut self, value: #elem) #ret_ty {
+                    self.#field.#push(value);
+                    #ret
+                }
+            };
+            (quote! { #elem }, quote! {}, q)
+        }
+        Collection::Set(elem, bounds) => {
+            let insert_fname = name("add", "insert");
+            let insert_doc = doc("Adds `value` to");
+            let remove_fname = name("remove", "remove");
+            let remove_doc = doc("Removes `value` from");
+            let q = quote! {
+                #[doc = #insert_doc]
+                ///
+                /// Returns false if the value was already present
+                #carry
+                pub fn #insert_fname(&mut self, value: #elem) -> bool
+                where
+                    #bounds
+                {
+                    self.#field.insert(value)
+                }
+                #[doc = #remove_doc]
+                ///
+                /// Returns false if the value wasn't present
+                #carry
+                pub fn #remove_fname(&mut self, value: &#elem) -> bool
+                where
+                    #bounds
+                {
+                    self.#field.remove(value)
+                }
+            };
+            (quote! { #elem }, bounds, q)
+        }
+        Collection::Map(key, value, bounds) => {
+            let insert_fname = name("add", "insert");
+            let insert_doc = doc("Adds `value` for `key` to");
+            let remove_fname = name("remove", "remove");
+            let remove_doc = doc("Removes the value for `key` from");
+            let q = quote! {
+                #[doc = #insert_doc]
+                ///
+                /// Returns the previous value for the key, if there was one
+                #carry
+                pub fn #insert_fname(
+                    &mut self,
+                    key: #key,
+                    value: #value,
+                ) -> ::core::option::Option<#value>
+                where
+                    #bounds
+                {
+                    self.#field.insert(key, value)
+                }
+                #[doc = #remove_doc]
+                ///
+                /// Returns the value for the key, if there was one
+                #carry
+                pub fn #remove_fname(&mut self, key: &#key) -> ::core::option::Option<#value>
+                where
+                    #bounds
+                {
+                    self.#field.remove(key)
+                }
+            };
+            (quote! { (#key, #value) }, bounds, q)
+        }
+    };
+    quote! {
+        #q
+        #[doc = #extend_doc]
+        #carry
+        pub fn #extend_fname(
+            &mut self,
+            iter: impl ::core::iter::IntoIterator<Item = #item>,
+        ) #ret_ty
+        where
+            #bounds
+        {
+            ::core::iter::Extend::extend(&mut self.#field, iter);
+            #ret
+        }
+        #[doc = #clear_doc]
+        #carry
+        pub fn #clear_fname(&mut self) #ret_ty {
+            self.#field.clear();
+            #ret
+        }
+    }
+}
@@ -0,0 +1,450 @@
+use ::proc_macro::{Delimiter, Group, TokenStream, TokenTree};
+use ::quote::{quote, ToTokens};
+use ::syn::{
+    ext::IdentExt, Attribute, Data, DataStruct, DeriveInput, Fields, GenericArgument, Ident, Meta,
+    NestedMeta, PathArguments, Type,
+};
+
+const ATTR_CFG: &str = "cfg";
+const ATTR_CFG_ATTR: &str = "cfg_attr";
+const ATTR_DEPRECATED: &str = "deprecated";
+
+/// A list of fields, each with its type and attributes
+pub(crate) type StructFields<'a> = Vec<(&'a Ident, &'a Type, &'a Vec<Attribute>)>;
+
+gen_typeset! {
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, str, bool, usize, isize, char, f32, f64
+}
+
+/// Returns the field names and their corresponding type from the AST (returning an error
+/// if it isn't a struct)
+pub fn get_struct_field_names(ast: &DeriveInput) -> Result<StructFields<'_>, TokenStream> {
+    let fields = match &ast.data {
+        Data::Struct(DataStruct {
+            fields: Fields::Named(fields),
+            ..
+        }) => &fields.named,
+        _ => {
+            return Err(
+                syn::Error::new_spanned(ast, "this macro can only be used on structs")
+                    .into_compile_error()
+                    .into(),
+            );
+        }
+    };
+    if fields.is_empty() {
+        Ok(Vec::new())
+    } else {
+        Ok(fields
+            .iter()
+            .map(|field| {
+                let fname = field.ident.as_ref().unwrap();
+                (fname, &field.ty, &field.attrs)
+            })
+            .collect())
+    }
+}
+
+/// Returns the name of a field with the `r#` prefix stripped, so that it can be used to build the
+/// names of the generated items (a `r#type` field gets `get_type` and not `get_r#type`). The field
+/// itself must still be accessed using the original (raw) ident
+pub(crate) fn get_field_name(field: &Ident) -> String {
+    field.unraw().to_string()
+}
+
+/// Returns true if `ty` is known to not have any drop glue. All of these types are `Copy` too
+pub(crate) fn has_no_drop_glue(ty: &Type) -> bool {
+    match ty {
+        Type::Path(t) => {
+            let type_str = t.clone().into_token_stream().to_string();
+            TYCOPY.contains(type_str.as_str())
+        }
+        Type::BareFn(_) | Type::Never(_) | Type::Ptr(_) | Type::Reference(_) => true,
+        Type::Array(arr) => self::has_no_drop_glue(&arr.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().all(self::has_no_drop_glue),
+        Type::Paren(paren) => self::has_no_drop_glue(&paren.elem),
+        Type::Group(group) => self::has_no_drop_glue(&group.elem),
+        _ => false,
+    }
+}
+
+/// The types from the standard library that own memory (or a reference count), and so have drop
+/// glue
+const OWNING_TYPES: &[&str] = &[
+    "String",
+    "Vec",
+    "VecDeque",
+    "LinkedList",
+    "BinaryHeap",
+    "HashMap",
+    "HashSet",
+    "BTreeMap",
+    "BTreeSet",
+    "Box",
+    "Rc",
+    "Arc",
+    "CString",
+    "OsString",
+    "PathBuf",
+];
+
+/// Returns true if `ty` is known to have drop glue, like a `String` or an `Option<Vec<T>>`. This
+/// only gives a better error for the common cases, since the compiler knows exactly which types
+/// have drop glue and rejects the others anyway
+pub(crate) fn has_drop_glue(ty: &Type) -> bool {
+    match ty {
+        Type::Path(t) if t.qself.is_none() => {
+            if let Some(inner) = self::get_option_inner(ty) {
+                return self::has_drop_glue(inner);
+            }
+            let segments = &t.path.segments;
+            // either a bare name, or a path in `std` or `alloc`
+            let is_std = match segments.first() {
+                _ if segments.len() == 1 => t.path.leading_colon.is_none(),
+                Some(krate) => krate.ident == "std" || krate.ident == "alloc",
+                None => false,
+            };
+            is_std
+                && segments
+                    .last()
+                    .is_some_and(|last| OWNING_TYPES.iter().any(|ty| last.ident == ty))
+        }
+        Type::Array(arr) => self::has_drop_glue(&arr.elem),
+        Type::Tuple(tuple) => tuple.elems.iter().any(self::has_drop_glue),
+        Type::Paren(paren) => self::has_drop_glue(&paren.elem),
+        Type::Group(group) => self::has_drop_glue(&group.elem),
+        _ => false,
+    }
+}
+
+/// Returns the `T` in `Option<T>`, if `ty` is an option (written as `Option<T>`, or with the full
+/// path in `std` or `core`)
+pub(crate) fn get_option_inner(ty: &Type) -> Option<&Type> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        Type::Group(group) => return self::get_option_inner(&group.elem),
+        _ => return None,
+    };
+    let segments: Vec<String> = path.segments.iter().map(|s| s.ident.to_string()).collect();
+    let is_option = match segments.as_slice() {
+        [option] => option == "Option" && path.leading_colon.is_none(),
+        [krate, module, option] => {
+            (krate == "std" || krate == "core") && module == "option" && option == "Option"
+        }
+        _ => false,
+    };
+    if !is_option {
+        return None;
+    }
+    match &path.segments.last()?.arguments {
+        PathArguments::AngleBracketed(args) if args.args.len() == 1 => match &args.args[0] {
+            GenericArgument::Type(inner) => Some(inner),
+            _ => None,
+        },
+        _ => None,
+    }
+}
+
+/// The kinds of collections that get element-level accessors, along with the types of their
+/// elements. Sets and maps also come with the bounds (for a `where` clause) that their keys need
+/// to be inserted or removed
+pub(crate) enum Collection<'a> {
+    /// `Vec<T>` and `VecDeque<T>`, along with the method used to push an element
+    Seq(&'a Type, &'static str),
+    /// `HashSet<T>` and `BTreeSet<T>`
+    Set(&'a Type, quote::__private::TokenStream),
+    /// `HashMap<K, V>` and `BTreeMap<K, V>`
+    Map(&'a Type, &'a Type, quote::__private::TokenStream),
+}
+
+/// The collections that are supported, in a form suitable for help text
+pub(crate) const SUPPORTED_COLLECTIONS: &str =
+    "`Vec`, `VecDeque`, `HashMap`, `BTreeMap`, `HashSet` and `BTreeSet`";
+
+/// Returns the kind of collection `ty` is, if it's one of the supported ones (by the name of the
+/// type, so `Vec<T>` and `std::vec::Vec<T>` are both detected)
+pub(crate) fn get_collection(ty: &Type) -> Option<Collection<'_>> {
+    let path = match ty {
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        Type::Group(group) => return self::get_collection(&group.elem),
+        _ => return None,
+    };
+    let last = path.segments.last()?;
+    let args: Vec<&Type> = match &last.arguments {
+        PathArguments::AngleBracketed(args) => args
+            .args
+            .iter()
+            .filter_map(|arg| match arg {
+                GenericArgument::Type(ty) => Some(ty),
+                _ => None,
+            })
+            .collect(),
+        _ => return None,
+    };
+    let hashed = |key: &Type, hasher: Option<&&Type>| {
+        let hasher = hasher.map(|hasher| quote! { #hasher: ::core::hash::BuildHasher, });
+        quote! { #key: ::core::hash::Hash + ::core::cmp::Eq, #hasher }
+    };
+    let ordered = |key: &Type| quote! { #key: ::core::cmp::Ord, };
+    // the allocator (for `Vec` and `VecDeque`) is ignored
+    match (last.ident.to_string().as_str(), args.as_slice()) {
+        ("Vec", [elem, ..]) => Some(Collection::Seq(elem, "push")),
+        ("VecDeque", [elem, ..]) => Some(Collection::Seq(elem, "push_back")),
+        ("HashSet", [elem, rest @ ..]) => Some(Collection::Set(elem, hashed(elem, rest.first()))),
+        ("BTreeSet", [elem, ..]) => Some(Collection::Set(elem, ordered(elem))),
+        ("HashMap", [key, value, rest @ ..]) => {
+            Some(Collection::Map(key, value, hashed(key, rest.first())))
+        }
+        ("BTreeMap", [key, value, ..]) => Some(Collection::Map(key, value, ordered(key))),
+        _ => None,
+    }
+}
+
+/// Returns a const-ed (if required) func "header"
+pub(crate) fn get_func_header(is_const: bool) -> quote::__private::TokenStream {
+    if is_const {
+        quote! {
+            pub const fn
+        }
+    } else {
+        quote! {
+            pub fn
+        }
+    }
+}
+
+/// Returns the candidate closest to `name`, if any of them is close enough to be a likely typo
+pub(crate) fn did_you_mean<'a>(
+    name: &str,
+    candidates: impl Iterator<Item = &'a str>,
+) -> Option<&'a str> {
+    candidates
+        .map(|candidate| (self::edit_distance(name, candidate), candidate))
+        .filter(|(distance, candidate)| *distance <= candidate.len().max(name.len()) / 3 + 1)
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, candidate)| candidate)
+}
+
+/// Returns the Levenshtein distance between `a` and `b`
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+    for (i, ca) in a.chars().enumerate() {
+        let mut cur = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = prev[j] + if ca == *cb { 0 } else { 1 };
+            cur.push(substitution.min(prev[j + 1] + 1).min(cur[j] + 1));
+        }
+        prev = cur;
+    }
+    prev[b.len()]
+}
+
+/// Returns true if `ident` appears anywhere in `tokens`
+pub(crate) fn mentions_ident(tokens: quote::__private::TokenStream, ident: &Ident) -> bool {
+    self::mentions(TokenStream::from(tokens), &ident.to_string())
+}
+
+fn mentions(tokens: TokenStream, ident: &str) -> bool {
+    tokens.into_iter().any(|tt| match tt {
+        TokenTree::Ident(i) => i.to_string() == ident,
+        TokenTree::Group(g) => self::mentions(g.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Returns `tokens` with every occurrence of `from` replaced with `to`
+pub(crate) fn replace_ident(
+    tokens: quote::__private::TokenStream,
+    from: &Ident,
+    to: &Ident,
+) -> quote::__private::TokenStream {
+    self::replace(
+        TokenStream::from(tokens),
+        &from.to_string(),
+        &to.to_string(),
+    )
+    .into()
+}
+
+fn replace(tokens: TokenStream, from: &str, to: &str) -> TokenStream {
+    tokens
+        .into_iter()
+        .map(|tt| match tt {
+            TokenTree::Ident(i) if i.to_string() == from => {
+                TokenTree::Ident(proc_macro::Ident::new(to, i.span()))
+            }
+            TokenTree::Group(g) => {
+                let mut group = Group::new(g.delimiter(), self::replace(g.stream(), from, to));
+                group.set_span(g.span());
+                TokenTree::Group(group)
+            }
+            tt => tt,
+        })
+        .collect()
+}
+
+/// Returns the tokens (usually a type) as they'd be written, like `Option<&'static str>`, for
+/// error messages. Printing the tokens as they are puts spaces between all of them, which would
+/// give `Option < & 'static str >`
+pub(crate) fn written(tokens: &impl ToTokens) -> String {
+    let mut out = String::new();
+    self::write_tokens(TokenStream::from(tokens.to_token_stream()), &mut out);
+    out
+}
+
+/// What was written last, to know if a space goes before the next token
+#[derive(Clone, Copy, PartialEq)]
+enum Written {
+    /// Nothing, or punctuation that the next token is written right after (like `<` or `&`)
+    Tight,
+    /// An identifier or a literal
+    Word,
+    /// A keyword that is followed by a space even before brackets (like `mut [T]`)
+    Keyword,
+    /// The `'` of a lifetime
+    Quote,
+    /// Punctuation that is followed by a space (like `,` or `+`)
+    Spaced,
+}
+
+fn write_tokens(tokens: TokenStream, out: &mut String) {
+    let mut last = Written::Tight;
+    let mut tokens = tokens.into_iter().peekable();
+    while let Some(tt) = tokens.next() {
+        match tt {
+            TokenTree::Ident(ident) => {
+                let name = ident.to_string();
+                if last != Written::Tight && last != Written::Quote {
+                    out.push(' ');
+                }
+                out.push_str(&name);
+                last = match name.as_str() {
+                    // a lifetime is followed by a space just like a keyword
+                    _ if last == Written::Quote => Written::Keyword,
+                    "mut" | "const" | "dyn" | "impl" | "as" => Written::Keyword,
+                    _ => Written::Word,
+                };
+            }
+            TokenTree::Literal(lit) => {
+                if last != Written::Tight {
+                    out.push(' ');
+                }
+                out.push_str(&lit.to_string());
+                last = Written::Word;
+            }
+            TokenTree::Group(group) => {
+                if last == Written::Keyword || last == Written::Spaced {
+                    out.push(' ');
+                }
+                let (open, close) = match group.delimiter() {
+                    Delimiter::Parenthesis => ("(", ")"),
+                    Delimiter::Bracket => ("[", "]"),
+                    Delimiter::Brace => ("{", "}"),
+                    Delimiter::None => ("", ""),
+                };
+                out.push_str(open);
+                self::write_tokens(group.stream(), out);
+                out.push_str(close);
+                last = Written::Word;
+            }
+            TokenTree::Punct(punct) => match punct.as_char() {
+                '\'' => {
+                    if last != Written::Tight {
+                        out.push(' ');
+                    }
+                    out.push('\'');
+                    last = Written::Quote;
+                }
+                ch @ (',' | ';') => {
+                    out.push(ch);
+                    last = Written::Spaced;
+                }
+                '-' if matches!(tokens.peek(), Some(TokenTree::Punct(p)) if p.as_char() == '>') => {
+                    tokens.next();
+                    out.push_str(" ->");
+                    last = Written::Spaced;
+                }
+                ch @ ('=' | '+') => {
+                    out.push(' ');
+                    out.push(ch);
+                    last = Written::Spaced;
+                }
+                ch => {
+                    out.push(ch);
+                    last = Written::Tight;
+                }
+            },
+        }
+    }
+}
+
+/// Returns the `#[cfg]` and `#[cfg_attr]` attributes on a field, so that anything generated for
+/// the field (ctor arguments, initializers) is compiled only when the field itself is
+pub(crate) fn get_cfg_attrs(attrs: &[Attribute]) -> quote::__private::TokenStream {
+    self::get_carried_attrs(attrs, false)
+}
+
+/// Same as [`get_cfg_attrs`], but also carries over `#[deprecated]`. This is meant for the
+/// accessors generated for a field, so that using them warns just like using the field would
+pub(crate) fn get_accessor_attrs(attrs: &[Attribute]) -> quote::__private::TokenStream {
+    self::get_carried_attrs(attrs, true)
+}
+
+fn get_carried_attrs(attrs: &[Attribute], deprecated: bool) -> quote::__private::TokenStream {
+    let mut ret = quote! {};
+    for attr in attrs {
+        if attr.path.is_ident(ATTR_CFG) || (deprecated && attr.path.is_ident(ATTR_DEPRECATED)) {
+            ret = quote! {
+                #ret
+                #attr
+            };
+        } else if attr.path.is_ident(ATTR_CFG_ATTR) {
+            // the attributes behind a `cfg_attr` are usually meant for the field (like
+            // `serde(...)`), so only keep the ones that make sense on the generated items
+            if let Some(cfg_attr) = attr
+                .parse_meta()
+                .ok()
+                .and_then(|meta| self::filter_cfg_attr(meta, deprecated))
+            {
+                ret = quote! {
+                    #ret
+                    #[#cfg_attr]
+                };
+            }
+        }
+    }
+    ret
+}
+
+/// Filters a `cfg_attr(predicate, attrs...)` down to the attributes that can be carried over,
+/// returning `None` if there's nothing left to carry
+fn filter_cfg_attr(meta: Meta, deprecated: bool) -> Option<quote::__private::TokenStream> {
+    let list = match meta {
+        Meta::List(list) => list,
+        _ => return None,
+    };
+    let mut nested = list.nested.into_iter();
+    let predicate = nested.next()?;
+    let kept: Vec<quote::__private::TokenStream> = nested
+        .filter_map(|attr| match attr {
+            NestedMeta::Meta(meta) if meta.path().is_ident(ATTR_CFG_ATTR) => {
+                self::filter_cfg_attr(meta, deprecated)
+            }
+            NestedMeta::Meta(meta)
+                if meta.path().is_ident(ATTR_CFG)
+                    || (deprecated && meta.path().is_ident(ATTR_DEPRECATED)) =>
+            {
+                Some(quote! { #meta })
+            }
+            _ => None,
+        })
+        .collect();
+    if kept.is_empty() {
+        None
+    } else {
+        Some(quote! {
+            cfg_attr(#predicate, #(#kept),*)
+        })
+    }
+}
@@ -8,268 +8,428 @@
 //! - [`Ctor`]: To generate constructors
 //! - [`Gtor`]: To generate getters
 //! - [`Stor`]: To generate setters
-//! - [`Constdef`]: To generate constant, compile-time default implementations
+//! - [`Constdef`]: To generate constant, compile-time default implementations (along with an
+//!   implementation of [`ConstDefault`], so that the structs can be nested in other ones)
+//! - [`ConstBounds`]: To generate `MIN` and `MAX` constants, with every field at its own minimum or
+//!   maximum
 //! - Full lifetimes, generics and `where` clause support
 //! - Use the `gtor` attribute to get either immutable or mutable or both references (see example below)
 //! - Skip generation of setters or getters with the `#[stor_skip]` or `#[gtor_skip]` attributes for
 //!   specific fields
-//! - Make ctors and gtors `const` with the `#[ctor_const]` and `#[gtor_const]` attributes
+//! - Make ctors, gtors and stors `const` with the `#[ctor_const]`, `#[gtor_const]` and
+//!   `#[stor_const]` attributes
 //! - Skip ctors, gtors and stors for `PhantomData` fields with the `#[phantom]` attribute
 //!
+//! ## Attributes
+//!
+//! All the macros understand the shared `#[derived(...)]` attribute, and each macro also has its
+//! own namespace: `#[ctor(...)]`, `#[gtor(...)]`, `#[stor(...)]` and `#[constdef(...)]`. The
+//! older attributes (like `#[gtor_skip]`) are just other spellings for keys in these namespaces,
+//! and continue to work.
+//!
+//! | Attribute                              | Used on | Same as                          |
+//! | -------------------------------------- | ------- | -------------------------------- |
+//! | `#[derived(const)]`                    | struct  | `#[ctor_const]`, `#[gtor_const]` |
+//! | `#[derived(phantom)]`                  | field   | `#[phantom]`                     |
+//! | `#[derived(skip)]`                     | field   | `#[gtor_skip]`, `#[stor_skip]`   |
+//! | `#[derived(skip(get))]`                | field   | `#[gtor_skip]`                   |
+//! | `#[derived(skip(set))]`                | field   | `#[stor_skip]`                   |
+//! | `#[derived(copy)]`                     | field   | `#[gtor_copy]`                   |
+//! | `#[ctor(const)]`                       | struct  | `#[ctor_const]`                  |
+//! | `#[gtor(const)]`                       | struct  | `#[gtor_const]`                  |
+//! | `#[gtor(get, get_mut)]`                | struct  |                                  |
+//! | `#[gtor(no_get)]`                      | struct  |                                  |
+//! | `#[gtor(copy)]`                        | field   | `#[gtor_copy]`                   |
+//! | `#[gtor(option)]`                      | both    |                                  |
+//! | `#[gtor(collection)]`                  | both    |                                  |
+//! | `#[gtor(skip)]`                        | field   | `#[gtor_skip]`                   |
+//! | `#[stor(const)]`                       | struct  | `#[stor_const]`                  |
+//! | `#[stor(skip)]`                        | field   | `#[stor_skip]`                   |
+//! | `#[stor(chain)]`                       | both    |                                  |
+//! | `#[stor(into)]`                        | both    |                                  |
+//! | `#[stor(option)]`                      | both    |                                  |
+//! | `#[stor(collection)]`                  | both    |                                  |
+//! | `#[stor(each = "name")]`               | field   |                                  |
+//! | `#[stor(map = path)]`                  | field   |                                  |
+//! | `#[stor(validate = path)]`             | field   |                                  |
+//! | `#[stor(error = Type)]`                | field   |                                  |
+//! | `#[stor(on_change = method)]`          | both    |                                  |
+//! | `#[stor(on_change_distinct = method)]` | both    |                                  |
+//! | `#[stor(generic)]`                     | field   |                                  |
+//! | `#[stor(replace)]`                     | both    |                                  |
+//! | `#[stor(take)]`                        | both    |                                  |
+//! | `#[stor(swap)]`                        | both    |                                  |
+//! | `#[stor(with)]`                        | both    |                                  |
+//! | `#[stor(with(const))]`                 | both    |                                  |
+//! | `#[constdef(default = value)]`         | field   |                                  |
+//! | `#[constdef(default)]`                 | variant |                                  |
+//! | `#[constdef(const_fn = name)]`         | struct  |                                  |
+//! | `#[constdef(no_const_fn)]`             | struct  |                                  |
+//! | `#[constdef(assoc_const)]`             | struct  |                                  |
+//! | `#[constdef(no_default_impl)]`         | struct  |                                  |
+//! | `#[constdef(zeroed)]`                  | struct  |                                  |
+//!
+//! Keys can be combined, like `#[derived(skip(get), copy)]`. Unknown keys are rejected, with a
+//! suggestion if they look like a typo.
+//!
+//! ```
+//! use derived::{Ctor, Gtor, Stor};
+//!
+//! #[derive(Ctor, Gtor, Stor)]
+//! #[derived(const)]
+//! pub struct Point {
+//!     x: u8,
+//!     #[derived(skip(set))]
+//!     y: u8,
+//! }
+//!
+//! const ORIGIN: Point = Point::new(0, 0);
+//! const ORIGIN_X: u8 = ORIGIN.get_x();
+//! ```
+//!
 
-use ::proc_macro::TokenStream;
-#[macro_use]
-mod macros;
-mod constdef;
-mod ctor;
-mod gtor;
-mod stor;
-mod util;
-
-#[proc_macro_derive(Ctor, attributes(ctor_const, phantom))]
-/// # Ctor: Get a constructor derived
-///
-/// The [`Ctor`] macro will take the fields in the order they are declared and generate a
-/// constructor, that is a `YourStruct::new()` function.
-///
-///
-/// ## Example
-/// ```
-/// use derived::Ctor;
-///
-/// #[derive(Ctor)]
-/// struct MyStruct {
-///     int: u32,
-///     unsigned_int: i32,
-/// }
-///
-/// let ms = MyStruct::new(1, -1);
-/// assert_eq!(ms.int, 1);
-/// assert_eq!(ms.unsigned_int, -1);
-/// ```
-///
-/// # Attributes
-///
-/// The following attributes are available:
-/// - `#[ctor_const]`: Will make your ctors constant
-/// - `#[phantom]`: Will skip the specified [`PhantomData`](core::marker::PhantomData) field(s) in
-/// the constructor, automatically adding `PhantomData` in the requisite positions
-///
-/// ## Constant constructors
-///
-/// To make your constructors `const`, simply add the `#[ctor_const]` attribute to the top
-/// of your struct.
-///
-/// ### Example
-///
-/// ```
-/// use derived::Ctor;
-///
-/// #[derive(Ctor)]
-/// #[ctor_const]
-/// pub struct MyConst {
-///     a: u8,
-///     b: u8,
-/// }
-/// // you can now use it in constant contexts
-/// const MC: MyConst = MyConst::new(1, 2);
-/// ```
-///
-pub fn derive_ctor(input: TokenStream) -> TokenStream {
-    ctor::derive_ctor(input)
-}
+pub use derived_macros::{ConstBounds, Constdef, Ctor, Gtor, Stor};
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::collections::{BTreeMap, BTreeSet, LinkedList, VecDeque};
+use std::marker::PhantomData;
+use std::mem::ManuallyDrop;
+use std::num::{Saturating, Wrapping};
+use std::ptr::NonNull;
+use std::sync::atomic::{
+    AtomicBool, AtomicI16, AtomicI32, AtomicI64, AtomicI8, AtomicIsize, AtomicPtr, AtomicU16,
+    AtomicU32, AtomicU64, AtomicU8, AtomicUsize,
+};
+use std::sync::{Condvar, Mutex, Once, OnceLock, RwLock};
+use std::time::Duration;
 
-#[proc_macro_derive(Gtor, attributes(gtor_const, gtor_copy, gtor_skip, phantom, gtor))]
-/// # Gtor: Get the getters derived
-///
-/// Gtor takes the fields in order and generates getters for each field. For example,
-/// if you have fields named `userid` and `name`, then the getters generated will be
-/// `get_userid` and `get_name`, returning references to the appropriate types. In other
-/// words, `get_*` named methods will be derived per your fields.
+/// A type with a default value that can be used in `const` contexts
 ///
-/// ## Example
-/// ```
-/// use derived::Gtor;
-/// #[derive(Gtor)]
-/// struct MyStruct {
-///     name: String,
-///     userid: u64,
-/// }
+/// This is implemented by [`Constdef`] for the types it's used on, and by this crate for the
+/// primitive types, the collections that can be created empty in a `const fn` (like [`String`] and
+/// [`Vec`]), the atomics, the synchronization primitives (like [`Mutex`]), the cells, options,
+/// pointers and a few other types from the standard library, along with arrays and tuples (of up
+/// to 12 elements) of types that implement it. [`Constdef`] uses it for the fields whose types it
+/// doesn't know, so a struct can have fields of other `Constdef` types, or of type parameters
+/// bound by `ConstDefault`:
 ///
-/// let ms = MyStruct { name: "Sayan".to_owned(), userid: 16 };
-/// assert_eq!(ms.get_name(), "Sayan");
 /// ```
-/// # Attributes
-///
-/// The following attributes are available:
-/// - `#[gtor_const]`: Will make your gtors constant
-/// - `#[gtor_skip]`: Will skip generation of getters for specific fields
-/// - `#[gtor_copy]`: Makes the getter return a copy of the value, assuming that the type is [`Copy`]
-/// - `#[phantom]`: Marks the field as a [`PhantomData`](core::marker::PhantomData) field, hence
-///     skipping getters, setters and ctors for the field
-/// - `#[gtor(...)]`: See [this example](#the-gtor-attribute)
-///
-/// ## The `gtor` attribute
-///
-/// Simply add the gtor attribute like this: `#[gtor(get, get_mut)]` on the top of your struct to
-/// get mutable and immutable reference methods to the fields in your struct.
-///
-/// ### Example
+/// use derived::{ConstDefault, Constdef};
 ///
-/// ```
-/// use derived::{Ctor, Gtor};
-/// #[derive(Ctor, Gtor)]
-/// #[gtor(get, get_mut)]
-/// pub struct Mutable {
-///     x_axis: u8,
-///     y_axis: u8,
+/// #[derive(Constdef)]
+/// pub struct Limits {
+///     max_connections: u32,
 /// }
 ///
-/// #[test]
-/// fn test_get_and_get_mut() {
-///     let mut m = Mutable::new(0, 0);
-///     // move x by 1 unit
-///     *m.get_x_axis_mut() = 1;
-///     // move y by 2 units
-///     *m.get_y_axis_mut() = 2;
-///     assert_eq!(m.get_x_axis(), 1);
-///     assert_eq!(m.get_y_axis(), 2);
+/// #[derive(Constdef)]
+/// pub struct Config<T: ConstDefault> {
+///     limits: Limits,
+///     extra: T,
 /// }
-/// ```
-///
-/// # Important notes
 ///
-/// ## References
-/// If any of the fields within the struct are primitive types that do not require large copies,
-/// then the value is returned directly instead of a reference to it:
-/// ```text
-/// u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, str, bool, usize, isize, char, f32, f64
+/// const CONFIG: Config<u8> = Config::default();
+/// assert_eq!(CONFIG.limits.max_connections, 0);
+/// assert_eq!(Config::<bool>::DEFAULT.extra, false);
 /// ```
-///
-/// ## Doc-comments
-///
-/// The [`Gtor`] macro will automatically add a doc comment of the form:
-/// ```text
-/// Returns the value for the `<struct_field>` field in struct [`<struct_name>`]
-/// ```
-///
-pub fn derive_gtor(input: TokenStream) -> TokenStream {
-    gtor::derive_gtor(input)
+pub trait ConstDefault: Sized {
+    /// The default value
+    const DEFAULT: Self;
 }
 
-#[proc_macro_derive(Stor, attributes(stor_skip, phantom))]
-/// # Stor: Get the setters derived
-///
-/// Stor takes the fields in order and generates setters for each field. For example,
-/// if you have fields named `userid` and `name`, then the setters generated will be
-/// `set_userid` and `set_name`, accepting values for the appropriate types. In other
-/// words, `set_*` named methods will be derived per your fields.
-///
-/// ## Example
-/// ```
-/// use derived::Stor;
-/// #[derive(Stor)]
-/// struct MyStruct {
-///     name: String,
-///     userid: u64,
-/// }
-///
-/// let mut ms = MyStruct { name: "Sayan".to_owned(), userid: 1 };
-/// assert_eq!(ms.name, "Sayan");
-/// assert_eq!(ms.userid, 1);
-/// ms.set_userid(0);
-/// assert_eq!(ms.userid, 0);
-/// ```
-///
-/// # Attributes
-///
-/// The following attributes are available:
-/// - `#[phantom]`: Skips the stor for the specified field(s), assuming they are
-/// [`PhantomData`](core::marker::PhantomData) fields. This has the same effect as `#[stor_skip]`
-/// but it makes it easier to use with the other macros, avoiding the need to write skips for phantom
-/// fields specifically
-/// - `#[stor_skip]`: Skips the stor for the specified field(s)
-///
-/// ## Doc-comments
-///
-/// The [`Stor`] macro will automatically add a doc comment of the form:
-/// ```text
-/// Sets the value for the `<struct_field>` field in struct [`<struct_name>`]
-/// ```
-///
-pub fn derive_stor(input: TokenStream) -> TokenStream {
-    stor::derive_stor(input)
+macro_rules! impl_const_default {
+    ($($ty:ty => $default:expr),*) => {
+        $(
+            impl ConstDefault for $ty {
+                const DEFAULT: Self = $default;
+            }
+        )*
+    };
 }
 
-#[proc_macro_derive(Constdef)]
-/// # `Constdef`: Constant, compile-time default implementations
-///
-/// Overcome the limits of the default trait to get constant, compile-time default implementations.
-///
-/// ## Why, and how?
-/// Implementations of the [`Default`](core::default::Default) trait cannot unfortunately be called
-/// in `const` contexts due to the [current limitations with traits per RFC 911](https://rust-lang.github.io/rfcs/0911-const-fn.html#detailed-design).
-/// To overcome this limitation, this crate _hacks around_ the problem by evaluating types at
-/// compile time and substituting requisite values. A `const fn default()` is implemented for the struct,
-/// along with the [`Default`] trait, enabling you to use it other contexts that need you to use
-/// default values, along with `const` contexts.
-///
-/// ## Example
-///
-/// ```
-/// use derived::Constdef;
-///
-/// #[derive(Constdef)]
-/// pub struct Constable {
-///     x: u8,
-///     boolean: bool,
-///     integer: i32,
-///     small_float: core::primitive::f32,
-///     big_float: std::primitive::f64,
-///     // arrays? check!
-///     num_array: [u8; 10],
-///     bool_array: [bool; 20],
-///     float_array: [f32; 30],
-///     // tuples? check!
-///     tuple: (u8, u16),
-///     // nested tuples? check!
-///     nested_tuple: ((u8, u8), u16),
-///     // nested arrays? check!
-///     nested_array: [[f32; 10]; 10],
-///     // tuples nested in arrays? check!
-///     nested_tuple_in_array: [(u8, u8); 10],
-///     // arrays nested in tuples? check!
-///     nested_array_in_tuple: (u8, [u8; 10]),
-/// }
-///
+impl_const_default! {
+    u8 => 0, i8 => 0, u16 => 0, i16 => 0, u32 => 0, i32 => 0, u64 => 0, i64 => 0,
+    u128 => 0, i128 => 0, usize => 0, isize => 0, f32 => 0.0, f64 => 0.0,
+    bool => false, char => '\0', () => ()
+}
+
+impl<T: ConstDefault, const N: usize> ConstDefault for [T; N] {
+    const DEFAULT: Self = [T::DEFAULT; N];
+}
+
+macro_rules! impl_const_default_tuple {
+    ($(($($ty:ident),*)),*) => {
+        $(
+            impl<$($ty: ConstDefault),*> ConstDefault for ($($ty,)*) {
+                const DEFAULT: Self = ($($ty::DEFAULT,)*);
+            }
+        )*
+    };
+}
+
+impl_const_default_tuple! {
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, K),
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+}
+
+impl ConstDefault for String {
+    const DEFAULT: Self = String::new();
+}
+
+macro_rules! impl_const_default_new {
+    ($($ty:ident<$($param:ident),*>),*) => {
+        $(
+            impl<$($param),*> ConstDefault for $ty<$($param),*> {
+                const DEFAULT: Self = $ty::new();
+            }
+        )*
+    };
+}
+
+impl_const_default_new! {
+    Vec<T>, VecDeque<T>, LinkedList<T>, BTreeMap<K, V>, BTreeSet<T>
+}
+
+impl_const_default! {
+    AtomicBool => AtomicBool::new(false), AtomicI8 => AtomicI8::new(0),
+    AtomicU8 => AtomicU8::new(0), AtomicI16 => AtomicI16::new(0), AtomicU16 => AtomicU16::new(0),
+    AtomicI32 => AtomicI32::new(0), AtomicU32 => AtomicU32::new(0),
+    AtomicI64 => AtomicI64::new(0), AtomicU64 => AtomicU64::new(0),
+    AtomicIsize => AtomicIsize::new(0), AtomicUsize => AtomicUsize::new(0),
+    Condvar => Condvar::new(), Once => Once::new()
+}
+
+impl<T> ConstDefault for AtomicPtr<T> {
+    const DEFAULT: Self = AtomicPtr::new(std::ptr::null_mut());
+}
+
+impl<T> ConstDefault for OnceLock<T> {
+    const DEFAULT: Self = OnceLock::new();
+}
+
+impl<T> ConstDefault for Option<T> {
+    const DEFAULT: Self = None;
+}
+
+impl<T: ?Sized> ConstDefault for PhantomData<T> {
+    const DEFAULT: Self = PhantomData;
+}
+
+macro_rules! impl_const_default_container {
+    ($($ty:ident => $new:expr),*) => {
+        $(
+            impl<T: ConstDefault> ConstDefault for $ty<T> {
+                const DEFAULT: Self = $new(T::DEFAULT);
+            }
+        )*
+    };
+}
+
+impl_const_default_container! {
+    Mutex => Mutex::new, RwLock => RwLock::new, Cell => Cell::new, RefCell => RefCell::new,
+    UnsafeCell => UnsafeCell::new, ManuallyDrop => ManuallyDrop::new, Wrapping => Wrapping,
+    Saturating => Saturating
+}
+
+impl_const_default! {
+    Duration => Duration::ZERO, &str => ""
+}
+
+impl<T> ConstDefault for &[T] {
+    const DEFAULT: Self = &[];
+}
+
+impl<T> ConstDefault for *const T {
+    const DEFAULT: Self = std::ptr::null();
+}
+
+impl<T> ConstDefault for *mut T {
+    const DEFAULT: Self = std::ptr::null_mut();
+}
+
+/// A type that is valid when all of its bytes are zero
 ///
-/// const CONSTABLE: Constable = Constable::default();
-/// assert_eq!(CONSTABLE.x, 0);
-/// assert!(!CONSTABLE.boolean);
-/// assert_eq!(CONSTABLE.integer, 0);
-/// assert_eq!(CONSTABLE.num_array, [0; 10]);
-/// assert_eq!(CONSTABLE.bool_array, [false; 20]);
-/// assert_eq!(CONSTABLE.float_array, [0.0; 30]);
-/// assert_eq!(CONSTABLE.small_float, 0.0);
-/// assert_eq!(CONSTABLE.big_float, 0.0);
-/// assert_eq!(CONSTABLE.tuple, (0, 0));
-/// assert_eq!(CONSTABLE.nested_tuple, ((0, 0), 0));
-/// assert_eq!(CONSTABLE.nested_array, [[0.0; 10]; 10]);
-/// assert_eq!(CONSTABLE.nested_tuple_in_array, [(0, 0); 10]);
-/// assert_eq!(CONSTABLE.nested_array_in_tuple, (0, [0; 10]));
-/// ```
+/// This is implemented by [`Constdef`] for the structs marked with `#[constdef(zeroed)]`, and by
+/// this crate for the primitive types, raw pointers (but not the ones to trait objects, whose
+/// vtables can't be null), the atomics, [`PhantomData`], the options
+/// that use zero for `None` (`Option<NonNull<T>>`, and options of function pointers with up to 12
+/// arguments), the cells and wrappers that have the same layout as what they hold, along with
+/// arrays and tuples of types that implement it. All the fields of a `zeroed` struct must be of
+/// types that implement it, so `zeroed` structs can be nested.
 ///
-/// ## Supported types
+/// # Safety
 ///
-/// - The following primitive types are supported:
-///     ```text
-///     u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, str, bool, usize, isize, char, f32, f64, ()
-///     ```
-/// - All arrays of the above types are supported
-/// - All tuples and nested tuples of the above types are supported
-/// - All nested arrays of the above types are supported
-/// - Nesting tuples inside arrays is supported
-/// - Nesting arrays inside tuples is supported
-pub fn derive_constdef(input: TokenStream) -> TokenStream {
-    constdef::derive(input)
+/// An all-zeroes value of the type must be valid, since [`Constdef`] creates the default value
+/// with [`core::mem::zeroed`]
+pub unsafe trait ConstZeroed {}
+
+macro_rules! impl_const_zeroed {
+    ($($ty:ty),*) => {
+        $(
+            unsafe impl ConstZeroed for $ty {}
+        )*
+    };
+}
+
+impl_const_zeroed! {
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64, bool, char, (),
+    AtomicBool, AtomicI8, AtomicU8, AtomicI16, AtomicU16, AtomicI32, AtomicU32, AtomicI64,
+    AtomicU64, AtomicIsize, AtomicUsize
+}
+
+unsafe impl<T: ConstZeroed, const N: usize> ConstZeroed for [T; N] {}
+unsafe impl<T: ?Sized> ConstZeroed for PhantomData<T> {}
+unsafe impl<T> ConstZeroed for AtomicPtr<T> {}
+unsafe impl<T> ConstZeroed for *const T {}
+unsafe impl<T> ConstZeroed for *mut T {}
+unsafe impl<T> ConstZeroed for *const [T] {}
+unsafe impl<T> ConstZeroed for *mut [T] {}
+unsafe impl ConstZeroed for *const str {}
+unsafe impl ConstZeroed for *mut str {}
+unsafe impl<T> ConstZeroed for Option<NonNull<T>> {}
+
+macro_rules! impl_const_zeroed_container {
+    ($($ty:ident),*) => {
+        $(
+            unsafe impl<T: ConstZeroed> ConstZeroed for $ty<T> {}
+        )*
+    };
+}
+
+impl_const_zeroed_container! {
+    Cell, UnsafeCell, ManuallyDrop, Wrapping, Saturating
+}
+
+macro_rules! impl_const_zeroed_tuple {
+    ($(($($ty:ident),*)),*) => {
+        $(
+            unsafe impl<$($ty: ConstZeroed),*> ConstZeroed for ($($ty,)*) {}
+        )*
+    };
+}
+
+impl_const_zeroed_tuple! {
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, K),
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+}
+
+macro_rules! impl_const_zeroed_fn {
+    ($(($($arg:ident),*)),*) => {
+        $(
+            unsafe impl<R, $($arg),*> ConstZeroed for Option<fn($($arg),*) -> R> {}
+            unsafe impl<R, $($arg),*> ConstZeroed for Option<unsafe fn($($arg),*) -> R> {}
+            unsafe impl<R, $($arg),*> ConstZeroed for Option<extern "C" fn($($arg),*) -> R> {}
+            unsafe impl<R, $($arg),*> ConstZeroed
+                for Option<unsafe extern "C" fn($($arg),*) -> R> {}
+        )*
+    };
+}
+
+impl_const_zeroed_fn! {
+    (),
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, K),
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+}
+
+/// A type with minimum and maximum values that can be used in `const` contexts
+///
+/// This is implemented by [`ConstBounds`](derive@ConstBounds) for the structs it's used on, and
+/// by this crate for the numeric types, `bool`, `char`, `()`, [`Wrapping`] and [`Saturating`],
+/// along with arrays and tuples (of up to 12 elements) of types that implement it.
+pub trait ConstBounds: Sized {
+    /// The minimum value
+    const MIN: Self;
+    /// The maximum value
+    const MAX: Self;
+}
+
+macro_rules! impl_const_bounds {
+    ($($ty:ty => $min:expr, $max:expr);*) => {
+        $(
+            impl ConstBounds for $ty {
+                const MIN: Self = $min;
+                const MAX: Self = $max;
+            }
+        )*
+    };
+    ($($ty:ident),*) => {
+        impl_const_bounds!($($ty => $ty::MIN, $ty::MAX);*);
+    };
+}
+
+impl_const_bounds! {
+    u8, i8, u16, i16, u32, i32, u64, i64, u128, i128, usize, isize, f32, f64
+}
+
+impl_const_bounds! {
+    bool => false, true; char => '\0', char::MAX; () => (), ()
+}
+
+impl<T: ConstBounds, const N: usize> ConstBounds for [T; N] {
+    const MIN: Self = [T::MIN; N];
+    const MAX: Self = [T::MAX; N];
+}
+
+macro_rules! impl_const_bounds_tuple {
+    ($(($($ty:ident),*)),*) => {
+        $(
+            impl<$($ty: ConstBounds),*> ConstBounds for ($($ty,)*) {
+                const MIN: Self = ($($ty::MIN,)*);
+                const MAX: Self = ($($ty::MAX,)*);
+            }
+        )*
+    };
+}
+
+impl_const_bounds_tuple! {
+    (A),
+    (A, B),
+    (A, B, C),
+    (A, B, C, D),
+    (A, B, C, D, E),
+    (A, B, C, D, E, F),
+    (A, B, C, D, E, F, G),
+    (A, B, C, D, E, F, G, H),
+    (A, B, C, D, E, F, G, H, I),
+    (A, B, C, D, E, F, G, H, I, J),
+    (A, B, C, D, E, F, G, H, I, J, K),
+    (A, B, C, D, E, F, G, H, I, J, K, L)
+}
+
+impl<T: ConstBounds> ConstBounds for Wrapping<T> {
+    const MIN: Self = Wrapping(T::MIN);
+    const MAX: Self = Wrapping(T::MAX);
+}
+
+impl<T: ConstBounds> ConstBounds for Saturating<T> {
+    const MIN: Self = Saturating(T::MIN);
+    const MAX: Self = Saturating(T::MAX);
 }
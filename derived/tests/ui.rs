@@ -0,0 +1,9 @@
+//! Compile-fail tests, checking the errors (and their spans, notes and help text) that the macros
+//! report. Run with `TRYBUILD=overwrite` to update the expected output
+//!
+
+#[test]
+fn test_ui() {
+    let t = trybuild::TestCases::new();
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,11 @@
+use derived::{Gtor, Stor};
+
+#[derive(Gtor, Stor)]
+pub struct NotCollections {
+    #[gtor(collection)]
+    count: u32,
+    #[stor(collection, each = "item")]
+    name: String,
+}
+
+fn main() {}
@@ -0,0 +1,12 @@
+use derived::{Gtor, Stor};
+
+#[derive(Gtor, Stor)]
+pub struct Duplicates {
+    #[gtor_skip]
+    #[gtor(skip)]
+    x: u8,
+    #[stor(with(const, const))]
+    y: u8,
+}
+
+fn main() {}
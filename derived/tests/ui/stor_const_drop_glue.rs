@@ -0,0 +1,11 @@
+use derived::Stor;
+
+#[derive(Stor)]
+#[stor_const]
+pub struct Named {
+    name: String,
+    nickname: Option<String>,
+    id: u64,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use derived::Stor;
+
+#[derive(Stor)]
+pub struct Pair<T> {
+    #[stor(generic)]
+    left: T,
+    right: Vec<T>,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use derived::{Gtor, Stor};
+
+#[derive(Gtor, Stor)]
+#[derived(cosnt)]
+pub struct Typo {
+    #[gtor(cpy)]
+    x: u8,
+}
+
+// `skip` can only be used on fields, so it isn't suggested here
+#[derive(Gtor)]
+#[derived(skp)]
+pub struct FieldOnly {
+    x: u8,
+}
+
+fn main() {}
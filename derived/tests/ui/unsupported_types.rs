@@ -0,0 +1,11 @@
+use derived::Constdef;
+
+// all the unsupported fields are reported at once
+#[derive(Constdef)]
+pub struct Unsupported {
+    a: &'static mut [u8],
+    b: &'static mut Option<u8>,
+    c: fn(u8) -> u8,
+}
+
+fn main() {}
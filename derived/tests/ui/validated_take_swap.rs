@@ -0,0 +1,17 @@
+use derived::Stor;
+
+fn check(value: &u8) -> Result<(), ()> {
+    if *value > 0 {
+        Ok(())
+    } else {
+        Err(())
+    }
+}
+
+#[derive(Stor)]
+pub struct Validated {
+    #[stor(validate = check, error = (), take, swap)]
+    value: u8,
+}
+
+fn main() {}
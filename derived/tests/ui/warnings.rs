@@ -0,0 +1,44 @@
+// the warnings are denied, so that they show up in the output
+#![deny(deprecated)]
+
+use derived::{Ctor, Gtor, Stor};
+use std::marker::PhantomData;
+
+#[derive(Ctor, Gtor, Stor)]
+#[ctor_const]
+#[derived(const)]
+pub struct Warnings<T> {
+    #[gtor_copy]
+    x: u8,
+    #[stor_skip]
+    #[derived(skip)]
+    y: u8,
+    // both `Gtor` and `Stor` read `#[derived(skip)]`, but `Stor` leaves the warning to `Gtor`,
+    // which is known to be derived from `#[gtor_copy]`
+    #[derived(skip)]
+    #[phantom]
+    _p: PhantomData<T>,
+    // `Ctor`, `Gtor` and `Stor` all read `#[phantom]`, but only `Ctor` warns, since it's known to
+    // be derived from `#[ctor_const]`
+    #[phantom]
+    #[derived(phantom)]
+    _q: PhantomData<T>,
+}
+
+// `Stor` warns on its own too
+#[derive(Stor)]
+pub struct StorOnly<T> {
+    #[derived(skip)]
+    #[phantom]
+    _p: PhantomData<T>,
+}
+
+#[derive(Gtor)]
+#[gtor(option)]
+pub struct Options {
+    // the gtors for options return the `u8` by value
+    #[gtor_copy]
+    x: Option<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use derived::{Constdef, Gtor};
+
+#[derive(Gtor)]
+#[gtor(copy)]
+pub struct OnStruct {
+    #[gtor(get_mut)]
+    x: u8,
+}
+
+#[derive(Constdef)]
+#[constdef(default)]
+pub struct DefaultStruct {
+    x: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use derived::Constdef;
+use std::ptr::NonNull;
+
+#[derive(Constdef)]
+#[constdef(zeroed)]
+pub struct Zeroed<'a> {
+    reference: &'a u8,
+    option: Option<u8>,
+    string: String,
+    non_null: NonNull<u8>,
+}
+
+fn main() {}